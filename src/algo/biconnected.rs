@@ -0,0 +1,252 @@
+use std::cmp::min;
+
+use visit::{
+    EdgeRef,
+    IntoEdgeReferences,
+    IntoNodeIdentifiers,
+    NodeIndexable,
+};
+
+/// [Generic] Return the articulation points of the graph.
+///
+/// An articulation point (or cut vertex) is a node whose removal, together
+/// with its edges, splits its connected component. Edge directions are
+/// ignored.
+///
+/// Using the iterative [Hopcroft–Tarjan algorithm][ht] with runtime
+/// **O(|V| + |E|)**.
+///
+/// Return the articulation points in the order of `node_identifiers`.
+///
+/// [ht]: https://en.wikipedia.org/wiki/Biconnected_component
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::UnGraph;
+/// use petgraph::algo::articulation_points;
+///
+/// // Two triangles sharing the node c.
+/// let mut g = UnGraph::<(), ()>::new_undirected();
+/// let a = g.add_node(());
+/// let b = g.add_node(());
+/// let c = g.add_node(());
+/// let d = g.add_node(());
+/// let e = g.add_node(());
+/// g.extend_with_edges(&[(a, b), (b, c), (c, a), (c, d), (d, e), (e, c)]);
+///
+/// assert_eq!(articulation_points(&g), vec![c]);
+/// ```
+pub fn articulation_points<G>(g: G) -> Vec<G::NodeId>
+    where G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+{
+    let search = Biconnected::new(g);
+    g.node_identifiers().filter(|&n| search.is_articulation[g.to_index(n)]).collect()
+}
+
+/// [Generic] Return the bridges of the graph.
+///
+/// A bridge (or cut edge) is an edge whose removal splits its connected
+/// component. Of two parallel edges, neither is a bridge. Edge directions
+/// are ignored.
+///
+/// Using the iterative [Hopcroft–Tarjan algorithm][ht] with runtime
+/// **O(|V| + |E|)**.
+///
+/// Return the bridges in the order of `edge_references`.
+///
+/// [ht]: https://en.wikipedia.org/wiki/Bridge_(graph_theory)
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::UnGraph;
+/// use petgraph::algo::bridges;
+///
+/// // A triangle with a tail.
+/// let mut g = UnGraph::<(), ()>::new_undirected();
+/// let a = g.add_node(());
+/// let b = g.add_node(());
+/// let c = g.add_node(());
+/// let d = g.add_node(());
+/// g.extend_with_edges(&[(a, b), (b, c), (c, a)]);
+/// let cd = g.add_edge(c, d, ());
+///
+/// assert_eq!(bridges(&g), vec![cd]);
+/// ```
+pub fn bridges<G>(g: G) -> Vec<G::EdgeId>
+    where G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+{
+    let search = Biconnected::new(g);
+    search.edges.iter().zip(&search.is_bridge)
+                .filter(|&(_, &is_bridge)| is_bridge)
+                .map(|(&e, _)| e)
+                .collect()
+}
+
+/// [Generic] Return the biconnected components of the graph.
+///
+/// A biconnected component is a maximal set of edges in which any two edges
+/// lie on a common simple cycle, or a single bridge. Every edge belongs to
+/// exactly one component, while an articulation point belongs to the
+/// components of all of its edges. Edge directions are ignored, and self
+/// loops are left out.
+///
+/// Using the iterative [Hopcroft–Tarjan algorithm][ht] with runtime
+/// **O(|V| + |E|)**.
+///
+/// Return the edges of each component.
+///
+/// [ht]: https://en.wikipedia.org/wiki/Biconnected_component
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::UnGraph;
+/// use petgraph::algo::biconnected_components;
+///
+/// // Two triangles sharing the node c, and a tail.
+/// let mut g = UnGraph::<(), ()>::new_undirected();
+/// let a = g.add_node(());
+/// let b = g.add_node(());
+/// let c = g.add_node(());
+/// let d = g.add_node(());
+/// let e = g.add_node(());
+/// let f = g.add_node(());
+/// g.extend_with_edges(&[(a, b), (b, c), (c, a), (c, d), (d, e), (e, c), (e, f)]);
+///
+/// let mut sizes: Vec<_> = biconnected_components(&g).iter().map(|c| c.len()).collect();
+/// sizes.sort();
+/// assert_eq!(sizes, vec![1, 3, 3]);
+/// ```
+pub fn biconnected_components<G>(g: G) -> Vec<Vec<G::EdgeId>>
+    where G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+{
+    let search = Biconnected::new(g);
+    let edges = &search.edges;
+    search.components.iter().map(|component| {
+        component.iter().map(|&e| edges[e]).collect()
+    }).collect()
+}
+
+/// The results of a depth first search for 2-connectivity, with edges
+/// numbered in the order of `edge_references`.
+struct Biconnected<E> {
+    edges: Vec<E>,
+    is_articulation: Vec<bool>,
+    is_bridge: Vec<bool>,
+    components: Vec<Vec<usize>>,
+}
+
+/// A node on the depth first search path.
+struct Frame {
+    node: usize,
+    /// The number of the edge the node was reached by.
+    tree_edge: Option<usize>,
+    /// The position of the next edge to follow in the node's adjacency list.
+    next: usize,
+}
+
+impl<E: Copy + PartialEq> Biconnected<E> {
+    fn new<G>(g: G) -> Self
+        where G: IntoEdgeReferences<EdgeId=E> + IntoNodeIdentifiers + NodeIndexable,
+    {
+        let n = g.node_bound();
+        let mut edges = Vec::new();
+        let mut adjacent = vec![Vec::new(); n];
+        for edge in g.edge_references() {
+            let (a, b) = (g.to_index(edge.source()), g.to_index(edge.target()));
+            if a != b {
+                adjacent[a].push((b, edges.len()));
+                adjacent[b].push((a, edges.len()));
+            }
+            edges.push(edge.id());
+        }
+
+        let mut result = Biconnected {
+            is_articulation: vec![false; n],
+            is_bridge: vec![false; edges.len()],
+            edges: edges,
+            components: Vec::new(),
+        };
+        // The discovery time of each node, and the earliest discovery time
+        // reachable from its subtree through at most one back edge.
+        let mut discovered: Vec<Option<usize>> = vec![None; n];
+        let mut low = vec![0; n];
+        let mut time = 0;
+        let mut path: Vec<Frame> = Vec::new();
+        let mut edge_stack = Vec::new();
+
+        for root in g.node_identifiers() {
+            let root = g.to_index(root);
+            if discovered[root].is_some() {
+                continue;
+            }
+            discovered[root] = Some(time);
+            low[root] = time;
+            time += 1;
+            let mut root_children = 0;
+            path.push(Frame { node: root, tree_edge: None, next: 0 });
+
+            while let Some((v, tree_edge, next)) =
+                path.last().map(|f| (f.node, f.tree_edge, f.next))
+            {
+                let v_time = discovered[v].unwrap();
+                if next < adjacent[v].len() {
+                    path.last_mut().unwrap().next += 1;
+                    let (w, e) = adjacent[v][next];
+                    if Some(e) == tree_edge {
+                        continue;
+                    }
+                    match discovered[w] {
+                        None => {
+                            edge_stack.push(e);
+                            discovered[w] = Some(time);
+                            low[w] = time;
+                            time += 1;
+                            path.push(Frame { node: w, tree_edge: Some(e), next: 0 });
+                        }
+                        Some(w_time) if w_time < v_time => {
+                            // A back edge to an ancestor.
+                            edge_stack.push(e);
+                            low[v] = min(low[v], w_time);
+                        }
+                        // A back edge from a descendant, seen from its end.
+                        Some(_) => {}
+                    }
+                    continue;
+                }
+
+                // All edges of `v` are done: report to its parent.
+                path.pop();
+                let (u, e) = match (path.last(), tree_edge) {
+                    (Some(parent), Some(e)) => (parent.node, e),
+                    _ => continue,
+                };
+                low[u] = min(low[u], low[v]);
+                let u_time = discovered[u].unwrap();
+                if low[v] >= u_time {
+                    // `u` separates the subtree of `v` from the rest; this
+                    // holds for every child of the root.
+                    if u == root {
+                        root_children += 1;
+                        if root_children > 1 {
+                            result.is_articulation[u] = true;
+                        }
+                    } else {
+                        result.is_articulation[u] = true;
+                    }
+                    let mut component = Vec::new();
+                    while let Some(top) = edge_stack.pop() {
+                        component.push(top);
+                        if top == e {
+                            break;
+                        }
+                    }
+                    result.components.push(component);
+                }
+                if low[v] > u_time {
+                    result.is_bridge[e] = true;
+                }
+            }
+        }
+        result
+    }
+}
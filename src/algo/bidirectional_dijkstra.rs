@@ -0,0 +1,183 @@
+use std::collections::{
+    BinaryHeap,
+    HashMap,
+};
+use std::collections::hash_map::Entry::{
+    Occupied,
+    Vacant,
+};
+use std::hash::Hash;
+
+use scored::MinScored;
+use visit::{
+    EdgeRef,
+    IntoEdgesDirected,
+    VisitMap,
+    Visitable,
+};
+use Direction::{
+    self,
+    Incoming,
+    Outgoing,
+};
+
+use super::Measure;
+
+/// [Generic] Bidirectional Dijkstra's shortest path algorithm.
+///
+/// Compute the shortest path from `start` to `goal`, including the total
+/// path cost, by searching forward from `start` and backward from `goal` (along
+/// incoming edges) at the same time. The search stops once the two searches
+/// have met and no shorter path is possible, which usually explores far fewer
+/// nodes than `dijkstra` does to reach `goal`.
+///
+/// The graph should be `Visitable` and implement `IntoEdgesDirected`. The
+/// function `edge_cost` should return the cost for a particular edge. Edge
+/// costs must be non-negative.
+///
+/// Returns the total cost + the path of subsequent `NodeId` from `start` to
+/// `goal`, if one was found.
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::bidirectional_dijkstra;
+///
+/// let mut g = Graph::new();
+/// let a = g.add_node(());
+/// let b = g.add_node(());
+/// let c = g.add_node(());
+/// let d = g.add_node(());
+/// let e = g.add_node(());
+/// g.extend_with_edges(&[
+///     (a, b, 2),
+///     (a, c, 1),
+///     (c, b, 0),
+///     (b, d, 4),
+///     (c, e, 3),
+///     (e, d, 2),
+/// ]);
+///
+/// let path = bidirectional_dijkstra(&g, a, d, |e| *e.weight());
+/// assert_eq!(path, Some((5, vec![a, c, b, d])));
+/// assert_eq!(bidirectional_dijkstra(&g, d, a, |e| *e.weight()), None);
+/// ```
+pub fn bidirectional_dijkstra<G, F, K>(graph: G, start: G::NodeId, goal: G::NodeId,
+                                       mut edge_cost: F)
+    -> Option<(K, Vec<G::NodeId>)>
+    where G: IntoEdgesDirected + Visitable,
+          G::NodeId: Eq + Hash,
+          F: FnMut(G::EdgeRef) -> K,
+          K: Measure + Copy,
+{
+    let mut forward = Search::new(graph, start);
+    let mut backward = Search::new(graph, goal);
+    // The best path found so far: its cost and the node where the searches met.
+    let mut best = if start == goal { Some((K::default(), start)) } else { None };
+
+    while let (Some(&MinScored(f_score, _)), Some(&MinScored(b_score, _))) =
+        (forward.visit_next.peek(), backward.visit_next.peek())
+    {
+        if let Some((cost, _)) = best {
+            if f_score + b_score >= cost {
+                break;
+            }
+        }
+        if f_score <= b_score {
+            forward.step(graph, Outgoing, &backward, &mut edge_cost, &mut best);
+        } else {
+            backward.step(graph, Incoming, &forward, &mut edge_cost, &mut best);
+        }
+    }
+
+    best.map(|(_, meet)| {
+        let mut path = forward.path_to(meet);
+        path.reverse();
+        path.extend(backward.path_to(meet).into_iter().skip(1));
+        (forward.scores[&meet] + backward.scores[&meet], path)
+    })
+}
+
+/// The state of one of the two searches.
+struct Search<N, K, VM> {
+    visited: VM,
+    scores: HashMap<N, K>,
+    predecessor: HashMap<N, N>,
+    visit_next: BinaryHeap<MinScored<K, N>>,
+}
+
+impl<N, K, VM> Search<N, K, VM>
+    where N: Copy + Eq + Hash,
+          K: Measure + Copy,
+          VM: VisitMap<N>,
+{
+    fn new<G>(graph: G, start: N) -> Self
+        where G: Visitable<NodeId=N, Map=VM>,
+    {
+        let mut search = Search {
+            visited: graph.visit_map(),
+            scores: HashMap::new(),
+            predecessor: HashMap::new(),
+            visit_next: BinaryHeap::new(),
+        };
+        search.scores.insert(start, K::default());
+        search.visit_next.push(MinScored(K::default(), start));
+        search
+    }
+
+    /// Settle the next node, following its edges in direction `dir`, and
+    /// update `best` where this search meets the `other` one.
+    fn step<G, F>(&mut self, graph: G, dir: Direction, other: &Self, edge_cost: &mut F,
+                  best: &mut Option<(K, N)>)
+        where G: IntoEdgesDirected<NodeId=N>,
+              F: FnMut(G::EdgeRef) -> K,
+    {
+        let MinScored(node_score, node) = match self.visit_next.pop() {
+            Some(next) => next,
+            None => return,
+        };
+        if !self.visited.visit(node) {
+            return;
+        }
+        for edge in graph.edges_directed(node, dir) {
+            let next = if edge.source() != node { edge.source() } else { edge.target() };
+            if self.visited.is_visited(&next) {
+                continue;
+            }
+            let next_score = node_score + edge_cost(edge);
+            match self.scores.entry(next) {
+                Occupied(ent) => if next_score < *ent.get() {
+                    *ent.into_mut() = next_score;
+                } else {
+                    continue;
+                },
+                Vacant(ent) => {
+                    ent.insert(next_score);
+                }
+            }
+            self.predecessor.insert(next, node);
+            self.visit_next.push(MinScored(next_score, next));
+            if let Some(&other_score) = other.scores.get(&next) {
+                let cost = next_score + other_score;
+                let improves = match *best {
+                    Some((best_cost, _)) => cost < best_cost,
+                    None => true,
+                };
+                if improves {
+                    *best = Some((cost, next));
+                }
+            }
+        }
+    }
+
+    /// Return the path from `node` back to the start of this search.
+    fn path_to(&self, node: N) -> Vec<N> {
+        let mut path = vec![node];
+        let mut current = node;
+        while let Some(&previous) = self.predecessor.get(&current) {
+            path.push(previous);
+            current = previous;
+        }
+        path
+    }
+}
@@ -0,0 +1,113 @@
+use std::collections::VecDeque;
+
+use visit::{
+    IntoNeighborsDirected,
+    IntoNodeIdentifiers,
+    NodeIndexable,
+    Visitable,
+    VisitMap,
+};
+use Direction::{
+    Incoming,
+    Outgoing,
+};
+
+use super::OddCycle;
+
+/// [Generic] Check whether the graph is bipartite, and find a two-coloring of
+/// its nodes if it is.
+///
+/// A graph is bipartite if its nodes can be split into two sides so that
+/// every edge joins nodes on different sides, which is the case exactly when
+/// it has no cycle of odd length. The graph is searched breadth first from
+/// each node that has not been reached yet, following edges in both
+/// directions, so the direction of edges is ignored.
+///
+/// Return a vector, indexed by node index, that holds `true` for the nodes
+/// on one side and `false` for the others, or an `OddCycle` error holding a
+/// cycle of odd length if the graph is not bipartite; in a directed graph,
+/// each node of the cycle is joined to the next by an edge in either
+/// direction. The first node of each weakly connected component is on the
+/// `false` side.
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::UnGraph;
+/// use petgraph::algo::bipartite_coloring;
+/// use petgraph::visit::NodeIndexable;
+///
+/// let mut g = UnGraph::<(), ()>::new_undirected();
+/// let a = g.add_node(());
+/// let b = g.add_node(());
+/// let c = g.add_node(());
+/// let d = g.add_node(());
+/// g.extend_with_edges(&[(a, b), (b, c), (c, d), (d, a)]);
+///
+/// let coloring = bipartite_coloring(&g).unwrap();
+/// assert_eq!(coloring, vec![false, true, false, true]);
+/// assert_ne!(coloring[g.to_index(a)], coloring[g.to_index(d)]);
+///
+/// // Closing a triangle makes it not bipartite.
+/// g.add_edge(a, c, ());
+/// let cycle = bipartite_coloring(&g).unwrap_err();
+/// assert_eq!(cycle.nodes().len(), 3);
+/// ```
+pub fn bipartite_coloring<G>(g: G) -> Result<Vec<bool>, OddCycle<G::NodeId>>
+    where G: IntoNeighborsDirected + IntoNodeIdentifiers + NodeIndexable + Visitable,
+{
+    let mut color = vec![false; g.node_bound()];
+    // The node that each node was discovered from.
+    let mut parent = vec![None; g.node_bound()];
+    let mut discovered = g.visit_map();
+    let mut queue = VecDeque::new();
+    for start in g.node_identifiers() {
+        if !discovered.visit(start) {
+            continue;
+        }
+        queue.push_back(start);
+        while let Some(node) = queue.pop_front() {
+            let i = g.to_index(node);
+            let neighbors = g.neighbors_directed(node, Outgoing)
+                             .chain(g.neighbors_directed(node, Incoming));
+            for next in neighbors {
+                let j = g.to_index(next);
+                if discovered.visit(next) {
+                    color[j] = !color[i];
+                    parent[j] = Some(node);
+                    queue.push_back(next);
+                } else if color[j] == color[i] {
+                    return Err(odd_cycle(g, &parent, node, next));
+                }
+            }
+        }
+    }
+    Ok(color)
+}
+
+/// Build the odd cycle closed by the edge from `a` to `b`, two nodes of the
+/// same color, out of their paths to their nearest common ancestor in the
+/// search tree.
+fn odd_cycle<G>(g: G, parent: &[Option<G::NodeId>], a: G::NodeId, b: G::NodeId)
+    -> OddCycle<G::NodeId>
+    where G: NodeIndexable,
+{
+    let mut a_path = vec![a];
+    let mut b_path = vec![b];
+    // Edges are followed both ways, so nodes of the same color are in the
+    // same search tree and at the same depth, and the two paths meet after
+    // the same number of steps.
+    let (mut x, mut y) = (a, b);
+    while x != y {
+        x = parent[g.to_index(x)].unwrap();
+        y = parent[g.to_index(y)].unwrap();
+        a_path.push(x);
+        b_path.push(y);
+    }
+    // Go up from `a` to the common ancestor, then down to `b`.
+    b_path.pop();
+    b_path.reverse();
+    a_path.extend(b_path);
+    OddCycle {
+        nodes: a_path,
+    }
+}
@@ -0,0 +1,432 @@
+//! Heuristics for coloring the nodes of a graph.
+//!
+//! A coloring assigns a color to each node so that the two ends of every
+//! edge have different colors; for example, nodes can be variables that
+//! need registers or exams that need time slots, with an edge between two of
+//! them when they can't share one. Finding a coloring with as few colors as
+//! possible is NP-hard, so these functions use heuristics that are fast and
+//! usually use few colors.
+//!
+//! Colors are numbered from `0`, and each function returns the color of
+//! every node together with the number of colors used. Edge directions are
+//! ignored, and self loops are ignored since they can't be satisfied.
+//!
+//! An edge coloring instead colors the edges, so that the edges at each node
+//! have different colors, which `edge_coloring` does.
+
+use std::collections::{
+    HashMap,
+    HashSet,
+};
+use std::cmp;
+use std::hash::Hash;
+
+use visit::{
+    EdgeRef,
+    IntoEdgeReferences,
+    IntoNodeIdentifiers,
+    NodeIndexable,
+};
+
+/// [Generic] Color the nodes greedily, in the given `order`.
+///
+/// Each node gets the smallest color that none of its neighbors has yet. The
+/// nodes that are not in `order` are colored after it, in the order of
+/// `node_identifiers`.
+///
+/// Runtime **O(|V| + |E|)**, not counting the time to remove parallel edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::UnGraph;
+/// use petgraph::algo::coloring::greedy_coloring;
+///
+/// // A path a - b - c - d.
+/// let mut g = UnGraph::<(), ()>::new_undirected();
+/// let a = g.add_node(());
+/// let b = g.add_node(());
+/// let c = g.add_node(());
+/// let d = g.add_node(());
+/// g.extend_with_edges(&[(a, b), (b, c), (c, d)]);
+///
+/// let (colors, n_colors) = greedy_coloring(&g, vec![a, b, c, d]);
+/// assert_eq!(n_colors, 2);
+/// assert_eq!(colors[&a], 0);
+/// assert_eq!(colors[&d], 1);
+///
+/// // The ends first: the middle nodes can't share either of their colors.
+/// let (_, n_colors) = greedy_coloring(&g, vec![a, d, b, c]);
+/// assert_eq!(n_colors, 3);
+/// ```
+pub fn greedy_coloring<G, I>(g: G, order: I) -> (HashMap<G::NodeId, usize>, usize)
+    where G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+          G::NodeId: Eq + Hash,
+          I: IntoIterator<Item=G::NodeId>,
+{
+    let adjacent = adjacency(g);
+    let order = order.into_iter()
+                     .chain(g.node_identifiers())
+                     .map(|n| g.to_index(n));
+    let color = greedy(&adjacent, order);
+    collect_colors(g, &color)
+}
+
+/// [Generic] Color the nodes with the DSatur heuristic.
+///
+/// The next node to color is always one whose neighbors already have the
+/// most distinct colors (its *saturation*), with ties broken by the number of
+/// its neighbors that are not colored yet; it gets the smallest color that
+/// none of its neighbors has. This colors bipartite graphs, cycles and
+/// complete graphs with the least possible number of colors.
+///
+/// Runtime **O(|V|² + |E|)**.
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::UnGraph;
+/// use petgraph::algo::coloring::dsatur_coloring;
+///
+/// // A wheel: a hub joined to each node of a cycle of five.
+/// let mut g = UnGraph::<(), ()>::new_undirected();
+/// let hub = g.add_node(());
+/// let rim: Vec<_> = (0..5).map(|_| g.add_node(())).collect();
+/// for i in 0..5 {
+///     g.add_edge(hub, rim[i], ());
+///     g.add_edge(rim[i], rim[(i + 1) % 5], ());
+/// }
+///
+/// let (colors, n_colors) = dsatur_coloring(&g);
+/// assert_eq!(n_colors, 4);
+/// assert!(rim.iter().all(|n| colors[n] != colors[&hub]));
+/// ```
+pub fn dsatur_coloring<G>(g: G) -> (HashMap<G::NodeId, usize>, usize)
+    where G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+          G::NodeId: Eq + Hash,
+{
+    let adjacent = adjacency(g);
+    let n = adjacent.len();
+    let mut color: Vec<Option<usize>> = vec![None; n];
+    // The colors of the neighbors of each node, as a set of flags.
+    let mut neighbor_colors: Vec<Vec<bool>> = vec![Vec::new(); n];
+    let mut saturation = vec![0; n];
+    let mut uncolored_degree: Vec<usize> = adjacent.iter().map(|a| a.len()).collect();
+
+    let nodes: Vec<usize> = g.node_identifiers().map(|n| g.to_index(n)).collect();
+    for _ in 0..nodes.len() {
+        let mut next = None;
+        for &i in &nodes {
+            if color[i].is_some() {
+                continue;
+            }
+            next = match next {
+                Some(j) if (saturation[j], uncolored_degree[j]) >=
+                           (saturation[i], uncolored_degree[i]) => Some(j),
+                _ => Some(i),
+            };
+        }
+        let i = next.unwrap();
+        let c = smallest_missing(&neighbor_colors[i]);
+        color[i] = Some(c);
+        for &j in &adjacent[i] {
+            uncolored_degree[j] -= 1;
+            let colors = &mut neighbor_colors[j];
+            if colors.len() <= c {
+                colors.resize(c + 1, false);
+            }
+            if !colors[c] {
+                colors[c] = true;
+                saturation[j] += 1;
+            }
+        }
+    }
+    collect_colors(g, &color)
+}
+
+/// [Generic] Color the nodes with the Welsh–Powell heuristic.
+///
+/// The nodes are colored greedily in order of decreasing degree, so that
+/// the nodes with the most neighbors get the first colors. This uses at most
+/// `k` colors, where `k` is the largest number such that `k` nodes have
+/// degree at least `k - 1`.
+///
+/// Runtime **O(|V|·log|V| + |E|)**, not counting the time to remove
+/// parallel edges.
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::UnGraph;
+/// use petgraph::algo::coloring::welsh_powell_coloring;
+///
+/// // A star with four leaves.
+/// let mut g = UnGraph::<(), ()>::new_undirected();
+/// let center = g.add_node(());
+/// for _ in 0..4 {
+///     let leaf = g.add_node(());
+///     g.add_edge(leaf, center, ());
+/// }
+///
+/// let (colors, n_colors) = welsh_powell_coloring(&g);
+/// assert_eq!(n_colors, 2);
+/// assert_eq!(colors[&center], 0);
+/// ```
+pub fn welsh_powell_coloring<G>(g: G) -> (HashMap<G::NodeId, usize>, usize)
+    where G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+          G::NodeId: Eq + Hash,
+{
+    let adjacent = adjacency(g);
+    let mut order: Vec<usize> = g.node_identifiers().map(|n| g.to_index(n)).collect();
+    order.sort_by(|&i, &j| adjacent[j].len().cmp(&adjacent[i].len()));
+    let color = greedy(&adjacent, order);
+    collect_colors(g, &color)
+}
+
+/// [Generic] Color the edges of the graph with the Misra–Gries algorithm.
+///
+/// The edges at each node get different colors. If Δ is the largest number
+/// of distinct neighbors of a node, at most Δ + 1 colors are used for a
+/// graph without parallel edges, which is at most one more than the least
+/// possible number. Of several parallel edges, the first is colored with the
+/// others, and the rest are colored afterwards with the smallest color that
+/// is free at both of their ends, which can take more colors.
+///
+/// Edge directions are ignored, and self loops are left out of the
+/// coloring.
+///
+/// Runtime **O(|E|·|V|)**.
+///
+/// Return the color of each edge and the number of colors used.
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::UnGraph;
+/// use petgraph::algo::coloring::edge_coloring;
+/// use petgraph::visit::EdgeRef;
+///
+/// // A round robin between four players: each color is a round.
+/// let mut g = UnGraph::<(), ()>::new_undirected();
+/// let p: Vec<_> = (0..4).map(|_| g.add_node(())).collect();
+/// for i in 0..4 {
+///     for j in i + 1..4 {
+///         g.add_edge(p[i], p[j], ());
+///     }
+/// }
+///
+/// let (colors, n_colors) = edge_coloring(&g);
+/// assert_eq!(colors.len(), 6);
+/// assert!(n_colors <= 4);
+/// for &a in &p {
+///     let mut rounds: Vec<_> = g.edges(a).map(|e| colors[&e.id()]).collect();
+///     rounds.sort();
+///     rounds.dedup();
+///     assert_eq!(rounds.len(), 3);
+/// }
+/// ```
+pub fn edge_coloring<G>(g: G) -> (HashMap<G::EdgeId, usize>, usize)
+    where G: IntoEdgeReferences + NodeIndexable,
+          G::EdgeId: Eq + Hash,
+{
+    // Split off the parallel edges.
+    let mut pairs = HashSet::new();
+    let mut simple = Vec::new();
+    let mut parallel = Vec::new();
+    for edge in g.edge_references() {
+        let (a, b) = (g.to_index(edge.source()), g.to_index(edge.target()));
+        if a == b {
+            continue;
+        }
+        if pairs.insert((cmp::min(a, b), cmp::max(a, b))) {
+            simple.push((a, b, edge.id()));
+        } else {
+            parallel.push((a, b, edge.id()));
+        }
+    }
+    let mut degree = vec![0; g.node_bound()];
+    for &(a, b, _) in &simple {
+        degree[a] += 1;
+        degree[b] += 1;
+    }
+    let max_degree = degree.into_iter().max().unwrap_or(0);
+
+    let mut colors = EdgeColors {
+        at: vec![vec![None; max_degree + 1]; g.node_bound()],
+    };
+    for &(u, v, _) in &simple {
+        colors.color_edge(u, v);
+    }
+    let mut edge_colors = HashMap::new();
+    let mut n_colors = 0;
+    for &(a, b, id) in &simple {
+        let c = colors.color_of(a, b).unwrap();
+        n_colors = cmp::max(n_colors, c + 1);
+        edge_colors.insert(id, c);
+    }
+    for &(a, b, id) in &parallel {
+        let c = (0..).find(|&c| colors.is_free(a, c) && colors.is_free(b, c)).unwrap();
+        colors.set(a, b, c);
+        n_colors = cmp::max(n_colors, c + 1);
+        edge_colors.insert(id, c);
+    }
+    (edge_colors, n_colors)
+}
+
+/// The colored edges of a graph, by node index: `at[a][c]` is the other end
+/// of the edge at `a` with color `c`.
+struct EdgeColors {
+    at: Vec<Vec<Option<usize>>>,
+}
+
+impl EdgeColors {
+    fn is_free(&self, a: usize, c: usize) -> bool {
+        match self.at[a].get(c) {
+            Some(w) => w.is_none(),
+            None => true,
+        }
+    }
+
+    fn free_color(&self, a: usize) -> usize {
+        (0..).find(|&c| self.is_free(a, c)).unwrap()
+    }
+
+    fn color_of(&self, a: usize, b: usize) -> Option<usize> {
+        self.at[a].iter().position(|&w| w == Some(b))
+    }
+
+    fn set(&mut self, a: usize, b: usize, c: usize) {
+        for &(x, y) in &[(a, b), (b, a)] {
+            if self.at[x].len() <= c {
+                self.at[x].resize(c + 1, None);
+            }
+            self.at[x][c] = Some(y);
+        }
+    }
+
+    fn unset(&mut self, a: usize, b: usize, c: usize) {
+        self.at[a][c] = None;
+        self.at[b][c] = None;
+    }
+
+    /// Color the uncolored edge between `u` and `v` with one of the colors
+    /// already in use, recoloring others as needed.
+    fn color_edge(&mut self, u: usize, v: usize) {
+        // A maximal fan of `u`: neighbors of `u` such that the edge to each
+        // one has a color that is free at the one before.
+        let mut fan = vec![v];
+        loop {
+            let last = *fan.last().unwrap();
+            let next = (0..self.at[u].len())
+                .filter(|&c| self.is_free(last, c))
+                .filter_map(|c| self.at[u][c])
+                .find(|w| !fan.contains(w));
+            match next {
+                Some(w) => fan.push(w),
+                None => break,
+            }
+        }
+
+        // Invert the path from `u` whose edges alternate between the colors
+        // `d` and `c`, so that `d` becomes free at `u`.
+        let c = self.free_color(u);
+        let d = self.free_color(*fan.last().unwrap());
+        let mut path = Vec::new();
+        let (mut x, mut color) = (u, d);
+        while let Some(y) = self.at[x][color] {
+            path.push((x, y, color));
+            x = y;
+            color = if color == d { c } else { d };
+        }
+        for &(x, y, color) in &path {
+            self.unset(x, y, color);
+        }
+        for &(x, y, color) in &path {
+            self.set(x, y, if color == d { c } else { d });
+        }
+
+        // Find a node of the fan where `d` is free, such that the fan up to
+        // it is still a fan, and rotate that part of the fan.
+        let mut end = 0;
+        loop {
+            if self.is_free(fan[end], d) {
+                break;
+            }
+            end += 1;
+            debug_assert!({
+                let shifted = self.color_of(u, fan[end]).unwrap();
+                self.is_free(fan[end - 1], shifted)
+            });
+        }
+        let shifted: Vec<usize> = (1..end + 1).map(|i| self.color_of(u, fan[i]).unwrap()).collect();
+        for (i, &color) in shifted.iter().enumerate() {
+            self.unset(u, fan[i + 1], color);
+        }
+        for (i, &color) in shifted.iter().enumerate() {
+            self.set(u, fan[i], color);
+        }
+        self.set(u, fan[end], d);
+    }
+}
+
+/// Return the neighbors of each node by node index, without self loops or
+/// repeated neighbors.
+fn adjacency<G>(g: G) -> Vec<Vec<usize>>
+    where G: IntoEdgeReferences + NodeIndexable,
+{
+    let mut adjacent = vec![Vec::new(); g.node_bound()];
+    for edge in g.edge_references() {
+        let (a, b) = (g.to_index(edge.source()), g.to_index(edge.target()));
+        if a != b {
+            adjacent[a].push(b);
+            adjacent[b].push(a);
+        }
+    }
+    for neighbors in &mut adjacent {
+        neighbors.sort();
+        neighbors.dedup();
+    }
+    adjacent
+}
+
+/// Color the nodes with the given indices in order, skipping repeated ones,
+/// each with the smallest color that none of its neighbors has.
+fn greedy<I>(adjacent: &[Vec<usize>], order: I) -> Vec<Option<usize>>
+    where I: IntoIterator<Item=usize>,
+{
+    let mut color = vec![None; adjacent.len()];
+    let mut taken = Vec::new();
+    for i in order {
+        if color[i].is_some() {
+            continue;
+        }
+        taken.clear();
+        for &j in &adjacent[i] {
+            if let Some(c) = color[j] {
+                if taken.len() <= c {
+                    taken.resize(c + 1, false);
+                }
+                taken[c] = true;
+            }
+        }
+        color[i] = Some(smallest_missing(&taken));
+    }
+    color
+}
+
+/// Return the smallest color that is not flagged in `taken`.
+fn smallest_missing(taken: &[bool]) -> usize {
+    taken.iter().position(|&t| !t).unwrap_or(taken.len())
+}
+
+/// Map each node to its color, and count the colors.
+fn collect_colors<G>(g: G, color: &[Option<usize>]) -> (HashMap<G::NodeId, usize>, usize)
+    where G: IntoNodeIdentifiers + NodeIndexable,
+          G::NodeId: Eq + Hash,
+{
+    let mut n_colors = 0;
+    let colors = g.node_identifiers().map(|n| {
+        let c = color[g.to_index(n)].unwrap();
+        if c >= n_colors {
+            n_colors = c + 1;
+        }
+        (n, c)
+    }).collect();
+    (colors, n_colors)
+}
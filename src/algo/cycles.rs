@@ -0,0 +1,265 @@
+use std::collections::VecDeque;
+
+use visit::{
+    EdgeRef,
+    IntoEdgeReferences,
+    IntoNodeIdentifiers,
+    NodeIndexable,
+};
+
+/// [Generic] Compute a cycle basis of an undirected graph: a set of cycles
+/// from which every cycle can be built by symmetric difference of edges.
+///
+/// The cycles are the fundamental cycles of a breadth first spanning forest
+/// (rooted at the first node of each connected component, in
+/// `node_identifiers` order): each edge not in the forest closes one cycle
+/// with the path between its ends in the forest. There are
+/// |E| - |V| + (the number of connected components) of them, and they are
+/// independent, as needed for Kirchhoff's circuit laws.
+///
+/// Edge directions are ignored. A self loop is a cycle of one node, and of
+/// two parallel edges, one can be in the forest while the other closes a
+/// cycle; so with parallel edges, two cycles can have the same nodes.
+///
+/// Runtime **O(|V|·|E|)**.
+///
+/// Return each cycle as its sequence of nodes: each node is joined to the
+/// next by an edge, and the last node to the first by the edge that is not in
+/// the forest. The cycles are in the order of those edges in
+/// `edge_references`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::UnGraph;
+/// use petgraph::algo::cycle_basis;
+///
+/// // Two squares sharing the edge b - e.
+/// let mut g = UnGraph::<(), ()>::new_undirected();
+/// let a = g.add_node(());
+/// let b = g.add_node(());
+/// let c = g.add_node(());
+/// let d = g.add_node(());
+/// let e = g.add_node(());
+/// let f = g.add_node(());
+/// g.extend_with_edges(&[(a, b), (b, c), (c, f), (f, e), (e, d), (d, a), (b, e)]);
+///
+/// let basis = cycle_basis(&g);
+/// assert_eq!(basis.len(), 2);
+/// assert!(basis.iter().all(|cycle| cycle.len() == 4));
+/// ```
+pub fn cycle_basis<G>(g: G) -> Vec<Vec<G::NodeId>>
+    where G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+{
+    let edges = Edges::new(g);
+    let n = g.node_bound();
+    let mut depth = vec![None; n];
+    let mut parent: Vec<Option<(usize, usize)>> = vec![None; n];
+    let mut in_tree = vec![false; edges.ends.len()];
+    let mut queue = VecDeque::new();
+    for root in g.node_identifiers() {
+        let root = g.to_index(root);
+        if depth[root].is_some() {
+            continue;
+        }
+        depth[root] = Some(0);
+        queue.push_back(root);
+        while let Some(a) = queue.pop_front() {
+            for &(b, e) in &edges.adjacent[a] {
+                if depth[b].is_none() {
+                    depth[b] = depth[a].map(|d| d + 1);
+                    parent[b] = Some((a, e));
+                    in_tree[e] = true;
+                    queue.push_back(b);
+                }
+            }
+        }
+    }
+
+    let mut cycles = Vec::new();
+    for (e, &(a, b)) in edges.ends.iter().enumerate() {
+        if in_tree[e] {
+            continue;
+        }
+        // Climb from both ends to their nearest common ancestor.
+        let mut a_path = vec![a];
+        let mut b_path = vec![b];
+        let (mut x, mut y) = (a, b);
+        while x != y {
+            if depth[x] >= depth[y] {
+                x = parent[x].unwrap().0;
+                a_path.push(x);
+            } else {
+                y = parent[y].unwrap().0;
+                b_path.push(y);
+            }
+        }
+        b_path.pop();
+        a_path.extend(b_path.into_iter().rev());
+        cycles.push(a_path.into_iter().map(|i| g.from_index(i)).collect());
+    }
+    cycles
+}
+
+/// [Generic] Find a shortest cycle through `node` in an undirected graph.
+///
+/// Edge directions are ignored. A self loop is a cycle of one node, and two
+/// parallel edges make a cycle of two nodes.
+///
+/// Using a breadth first search from `node` with runtime **O(|V| + |E|)**.
+///
+/// Return the nodes of the cycle, starting with `node`: each node is joined
+/// to the next by an edge, and the last node to the first. Return `None` if
+/// no cycle goes through `node`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::UnGraph;
+/// use petgraph::algo::shortest_cycle_through;
+///
+/// // A triangle and a square sharing the node a.
+/// let mut g = UnGraph::<(), ()>::new_undirected();
+/// let a = g.add_node(());
+/// let b = g.add_node(());
+/// let c = g.add_node(());
+/// let d = g.add_node(());
+/// let e = g.add_node(());
+/// let f = g.add_node(());
+/// g.extend_with_edges(&[(a, b), (b, c), (c, a), (a, d), (d, e), (e, f), (f, a)]);
+///
+/// assert_eq!(shortest_cycle_through(&g, a).map(|c| c.len()), Some(3));
+/// let square = shortest_cycle_through(&g, e).unwrap();
+/// assert_eq!(square[0], e);
+/// assert_eq!(square.len(), 4);
+/// assert!(square.contains(&a));
+/// ```
+pub fn shortest_cycle_through<G>(g: G, node: G::NodeId) -> Option<Vec<G::NodeId>>
+    where G: IntoEdgeReferences + NodeIndexable,
+{
+    let edges = Edges::new(g);
+    edges.shortest_cycle_through(g.to_index(node))
+         .map(|cycle| cycle.into_iter().map(|i| g.from_index(i)).collect())
+}
+
+/// [Generic] Return the girth of an undirected graph: the length of its
+/// shortest cycle, or `None` if it has no cycles.
+///
+/// Edge directions are ignored. A self loop is a cycle of length one, and
+/// two parallel edges make a cycle of length two.
+///
+/// Using a breadth first search from each node with runtime
+/// **O(|V|·(|V| + |E|))**.
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::UnGraph;
+/// use petgraph::algo::girth;
+///
+/// // The Petersen graph.
+/// let mut g = UnGraph::<(), ()>::new_undirected();
+/// let outer: Vec<_> = (0..5).map(|_| g.add_node(())).collect();
+/// let inner: Vec<_> = (0..5).map(|_| g.add_node(())).collect();
+/// for i in 0..5 {
+///     g.add_edge(outer[i], outer[(i + 1) % 5], ());
+///     g.add_edge(outer[i], inner[i], ());
+///     g.add_edge(inner[i], inner[(i + 2) % 5], ());
+/// }
+///
+/// assert_eq!(girth(&g), Some(5));
+/// ```
+pub fn girth<G>(g: G) -> Option<usize>
+    where G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+{
+    let edges = Edges::new(g);
+    g.node_identifiers()
+     .filter_map(|n| edges.shortest_cycle_through(g.to_index(n)).map(|c| c.len()))
+     .min()
+}
+
+/// The edges of a graph, numbered in `edge_references` order, by node index.
+struct Edges {
+    /// The ends of each edge.
+    ends: Vec<(usize, usize)>,
+    /// The other end and number of each edge at each node, except for self
+    /// loops.
+    adjacent: Vec<Vec<(usize, usize)>>,
+    has_self_loop: Vec<bool>,
+}
+
+impl Edges {
+    fn new<G>(g: G) -> Self
+        where G: IntoEdgeReferences + NodeIndexable,
+    {
+        let mut edges = Edges {
+            ends: Vec::new(),
+            adjacent: vec![Vec::new(); g.node_bound()],
+            has_self_loop: vec![false; g.node_bound()],
+        };
+        for edge in g.edge_references() {
+            let (a, b) = (g.to_index(edge.source()), g.to_index(edge.target()));
+            let e = edges.ends.len();
+            edges.ends.push((a, b));
+            if a == b {
+                edges.has_self_loop[a] = true;
+            } else {
+                edges.adjacent[a].push((b, e));
+                edges.adjacent[b].push((a, e));
+            }
+        }
+        edges
+    }
+
+    fn shortest_cycle_through(&self, root: usize) -> Option<Vec<usize>> {
+        if self.has_self_loop[root] {
+            return Some(vec![root]);
+        }
+        // Search from `root`, noting which child of `root` each node was
+        // reached through: an edge that joins two of those branches, or that
+        // leads back to `root` other than from its parent, closes a cycle.
+        let n = self.adjacent.len();
+        let mut depth = vec![0; n];
+        let mut parent: Vec<Option<(usize, usize)>> = vec![None; n];
+        let mut branch = vec![None; n];
+        let mut best: Option<(usize, usize, usize)> = None;
+        let mut queue = VecDeque::new();
+        branch[root] = Some(root);
+        queue.push_back(root);
+        while let Some(a) = queue.pop_front() {
+            for &(b, e) in &self.adjacent[a] {
+                if parent[a].map(|(_, pe)| pe) == Some(e) {
+                    continue;
+                }
+                if branch[b].is_none() {
+                    depth[b] = depth[a] + 1;
+                    parent[b] = Some((a, e));
+                    branch[b] = if a == root { Some(b) } else { branch[a] };
+                    queue.push_back(b);
+                } else if a == root || b == root || branch[a] != branch[b] {
+                    let len = depth[a] + depth[b] + 1;
+                    best = match best {
+                        Some((l, _, _)) if l <= len => best,
+                        _ => Some((len, a, b)),
+                    };
+                }
+            }
+        }
+
+        best.map(|(_, a, b)| {
+            let path_from_root = |mut x: usize| {
+                let mut path = vec![x];
+                while let Some((p, _)) = parent[x] {
+                    path.push(p);
+                    x = p;
+                }
+                path.reverse();
+                path
+            };
+            // Go down to `a`, then back up from `b`.
+            let (a, b) = if a == root { (b, a) } else { (a, b) };
+            let mut cycle = path_from_root(a);
+            if b != root {
+                cycle.extend(path_from_root(b).into_iter().skip(1).rev());
+            }
+            cycle
+        })
+    }
+}
@@ -0,0 +1,155 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use visit::{
+    EdgeRef,
+    IntoEdges,
+    IntoNeighborsDirected,
+    IntoNodeIdentifiers,
+    Visitable,
+};
+
+use super::{
+    toposort,
+    Cycle,
+    Measure,
+};
+
+/// [Generic] Compute shortest paths from `source` in a directed acyclic graph.
+///
+/// The nodes are relaxed in topological order, which takes linear time,
+/// **O(|V| + |E|)**. Unlike `dijkstra`, negative edge costs are permitted.
+///
+/// The graph should be `Visitable` and implement `IntoEdges`,
+/// `IntoNeighborsDirected` and `IntoNodeIdentifiers`. The function
+/// `edge_cost` should return the cost for a particular edge.
+///
+/// If the graph is acyclic, return a map of path costs and a map of
+/// predecessors, which have an entry for each node reachable from `source`
+/// (`source` itself has no predecessor). Otherwise, return a `Cycle` error.
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::dag_shortest_paths;
+///
+/// let mut g = Graph::new();
+/// let a = g.add_node(());
+/// let b = g.add_node(());
+/// let c = g.add_node(());
+/// let d = g.add_node(());
+/// g.extend_with_edges(&[
+///     (a, b, 3),
+///     (a, c, 1),
+///     (b, c, -4),
+///     (c, d, 2),
+/// ]);
+///
+/// let (distances, predecessors) = dag_shortest_paths(&g, a, |e| *e.weight()).unwrap();
+/// assert_eq!(distances[&c], -1);
+/// assert_eq!(distances[&d], 1);
+/// assert_eq!(predecessors[&c], b);
+/// assert!(!predecessors.contains_key(&a));
+///
+/// g.add_edge(d, a, 0);
+/// assert!(dag_shortest_paths(&g, a, |e| *e.weight()).is_err());
+/// ```
+pub fn dag_shortest_paths<G, F, K>(g: G, source: G::NodeId, edge_cost: F)
+    -> Result<(HashMap<G::NodeId, K>, HashMap<G::NodeId, G::NodeId>), Cycle<G::NodeId>>
+    where G: IntoEdges + IntoNeighborsDirected + IntoNodeIdentifiers + Visitable,
+          G::NodeId: Eq + Hash,
+          F: FnMut(G::EdgeRef) -> K,
+          K: Measure + Copy,
+{
+    dag_paths(g, source, edge_cost, |a, b| a < b)
+}
+
+/// [Generic] Compute longest paths from `source` in a directed acyclic graph.
+///
+/// The longest path to a node is its *critical path*: in a scheduling graph
+/// where edge costs are durations, it is the earliest time that the node's
+/// task can start. The nodes are relaxed in topological order, which takes
+/// linear time, **O(|V| + |E|)**. Negative edge costs are permitted.
+///
+/// The graph should be `Visitable` and implement `IntoEdges`,
+/// `IntoNeighborsDirected` and `IntoNodeIdentifiers`. The function
+/// `edge_cost` should return the cost for a particular edge.
+///
+/// If the graph is acyclic, return a map of path costs and a map of
+/// predecessors, which have an entry for each node reachable from `source`
+/// (`source` itself has no predecessor). Otherwise, return a `Cycle` error.
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::dag_longest_path;
+///
+/// // Tasks, with an edge from each task to the ones that depend on it.
+/// let mut g = Graph::new();
+/// let start = g.add_node("start");
+/// let fetch = g.add_node("fetch");
+/// let configure = g.add_node("configure");
+/// let compile = g.add_node("compile");
+/// g.extend_with_edges(&[
+///     (start, fetch, 0),
+///     (start, configure, 0),
+///     (fetch, compile, 5),
+///     (configure, compile, 2),
+/// ]);
+///
+/// let (distances, predecessors) = dag_longest_path(&g, start, |e| *e.weight()).unwrap();
+/// assert_eq!(distances[&compile], 5);
+///
+/// // Follow the predecessors to recover the critical path.
+/// let mut path = vec![compile];
+/// while let Some(&prev) = predecessors.get(path.last().unwrap()) {
+///     path.push(prev);
+/// }
+/// path.reverse();
+/// assert_eq!(path, vec![start, fetch, compile]);
+/// ```
+pub fn dag_longest_path<G, F, K>(g: G, source: G::NodeId, edge_cost: F)
+    -> Result<(HashMap<G::NodeId, K>, HashMap<G::NodeId, G::NodeId>), Cycle<G::NodeId>>
+    where G: IntoEdges + IntoNeighborsDirected + IntoNodeIdentifiers + Visitable,
+          G::NodeId: Eq + Hash,
+          F: FnMut(G::EdgeRef) -> K,
+          K: Measure + Copy,
+{
+    dag_paths(g, source, edge_cost, |a, b| a > b)
+}
+
+/// Relax the edges of every node reachable from `source`, in topological
+/// order, keeping a path cost when it is `better` than the current one.
+fn dag_paths<G, F, K, B>(g: G, source: G::NodeId, mut edge_cost: F, better: B)
+    -> Result<(HashMap<G::NodeId, K>, HashMap<G::NodeId, G::NodeId>), Cycle<G::NodeId>>
+    where G: IntoEdges + IntoNeighborsDirected + IntoNodeIdentifiers + Visitable,
+          G::NodeId: Eq + Hash,
+          F: FnMut(G::EdgeRef) -> K,
+          K: Measure + Copy,
+          B: Fn(K, K) -> bool,
+{
+    let order = toposort(g, None)?;
+    let mut distances = HashMap::new();
+    let mut predecessors = HashMap::new();
+    distances.insert(source, K::default());
+
+    for node in order {
+        let node_score = match distances.get(&node) {
+            Some(&score) => score,
+            None => continue,
+        };
+        for edge in g.edges(node) {
+            let next = edge.target();
+            let next_score = node_score + edge_cost(edge);
+            let improves = match distances.get(&next) {
+                Some(&score) => better(next_score, score),
+                None => true,
+            };
+            if improves {
+                distances.insert(next, next_score);
+                predecessors.insert(next, node);
+            }
+        }
+    }
+    Ok((distances, predecessors))
+}
@@ -0,0 +1,224 @@
+use visit::{
+    EdgeRef,
+    GraphProp,
+    IntoEdgeReferences,
+    IntoNodeIdentifiers,
+    NodeIndexable,
+};
+
+use super::EulerianError;
+
+/// [Generic] Find an Eulerian circuit: a closed walk that uses every edge of
+/// the graph exactly once.
+///
+/// One exists if the edges are all connected to each other and, in an
+/// undirected graph, every node has an even degree, or, in a directed graph,
+/// every node has as many incoming as outgoing edges. Nodes without edges
+/// don't matter, and a self loop counts twice towards the degree of its node.
+///
+/// Using [Hierholzer's algorithm][hierholzer] with runtime **O(|V| + |E|)**.
+///
+/// Return the edges in the order they are walked, starting and ending at the
+/// source of the first edge in `edge_references` order; in an undirected
+/// graph, each edge shares a node with the next one. Return an error saying
+/// why there is no circuit otherwise.
+///
+/// [hierholzer]: https://en.wikipedia.org/wiki/Eulerian_path#Hierholzer's_algorithm
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::{eulerian_circuit, EulerianError};
+///
+/// let mut g = Graph::<(), ()>::new();
+/// let a = g.add_node(());
+/// let b = g.add_node(());
+/// let c = g.add_node(());
+/// let ab = g.add_edge(a, b, ());
+/// let ba = g.add_edge(b, a, ());
+/// let bc = g.add_edge(b, c, ());
+/// let ca = g.add_edge(c, a, ());
+///
+/// assert_eq!(eulerian_circuit(&g), Err(EulerianError::Unbalanced(vec![a, b])));
+/// let ab2 = g.add_edge(a, b, ());
+/// assert_eq!(eulerian_circuit(&g), Ok(vec![ab, ba, ab2, bc, ca]));
+/// ```
+pub fn eulerian_circuit<G>(g: G) -> Result<Vec<G::EdgeId>, EulerianError<G::NodeId>>
+    where G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    let walk = Walk::new(g);
+    let unbalanced = walk.unbalanced(g);
+    if !unbalanced.is_empty() {
+        return Err(EulerianError::Unbalanced(unbalanced));
+    }
+    match walk.tails.first() {
+        Some(&start) => walk.walk(start),
+        None => Ok(Vec::new()),
+    }
+}
+
+/// [Generic] Find an Eulerian path: a walk that uses every edge of the graph
+/// exactly once.
+///
+/// One exists if the edges are all connected to each other and, in an
+/// undirected graph, at most two nodes have an odd degree, or, in a directed
+/// graph, every node has as many incoming as outgoing edges except for at
+/// most one node with an extra outgoing edge, where the path starts, and one
+/// with an extra incoming edge, where it ends. Nodes without edges don't
+/// matter, and a self loop counts twice towards the degree of its node.
+///
+/// Using [Hierholzer's algorithm][hierholzer] with runtime **O(|V| + |E|)**.
+///
+/// Return the edges in the order they are walked; in an undirected graph,
+/// each edge shares a node with the next one. If the path can't be closed,
+/// it starts at the first node with an odd degree, or with an extra
+/// outgoing edge, in `node_identifiers` order; otherwise it is a circuit as
+/// from `eulerian_circuit`. Return an error saying why there is no path
+/// otherwise.
+///
+/// [hierholzer]: https://en.wikipedia.org/wiki/Eulerian_path#Hierholzer's_algorithm
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::UnGraph;
+/// use petgraph::algo::{eulerian_path, EulerianError};
+///
+/// // A house: a square with a roof, drawn without lifting the pen.
+/// let mut g = UnGraph::<(), ()>::new_undirected();
+/// let a = g.add_node(());
+/// let b = g.add_node(());
+/// let c = g.add_node(());
+/// let d = g.add_node(());
+/// let roof = g.add_node(());
+/// g.extend_with_edges(&[(a, b), (b, c), (c, d), (d, a), (a, c), (c, roof), (roof, d)]);
+///
+/// // It starts at a, one of the two nodes of odd degree.
+/// let path = eulerian_path(&g).unwrap();
+/// assert_eq!(path.len(), 7);
+/// assert_eq!(g.edge_endpoints(path[0]), Some((a, b)));
+///
+/// // A chimney sticks out too far.
+/// let chimney = g.add_node(());
+/// g.add_edge(roof, chimney, ());
+/// assert_eq!(eulerian_path(&g), Err(EulerianError::Unbalanced(vec![a, d, roof, chimney])));
+/// ```
+pub fn eulerian_path<G>(g: G) -> Result<Vec<G::EdgeId>, EulerianError<G::NodeId>>
+    where G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+{
+    let walk = Walk::new(g);
+    let unbalanced = walk.unbalanced(g);
+    let start = if unbalanced.is_empty() {
+        match walk.tails.first() {
+            Some(&start) => start,
+            None => return Ok(Vec::new()),
+        }
+    } else {
+        // The path must start at a node with an extra outgoing edge, or
+        // either node of odd degree, and end at the other.
+        let balance = |n: G::NodeId| walk.balance[g.to_index(n)];
+        let fits = if g.is_directed() {
+            unbalanced.len() == 2 && balance(unbalanced[0]) * balance(unbalanced[1]) == -1
+        } else {
+            unbalanced.len() == 2
+        };
+        if !fits {
+            return Err(EulerianError::Unbalanced(unbalanced));
+        }
+        let start = if balance(unbalanced[0]) >= 0 { unbalanced[0] } else { unbalanced[1] };
+        g.to_index(start)
+    };
+    walk.walk(start)
+}
+
+/// The edges of a graph, numbered in `edge_references` order, for walking.
+struct Walk<E> {
+    edges: Vec<E>,
+    /// The node index at each end of each edge.
+    tails: Vec<usize>,
+    heads: Vec<usize>,
+    /// The edges that can be walked out of each node.
+    adjacent: Vec<Vec<usize>>,
+    /// For a directed graph, the number of outgoing minus incoming edges of
+    /// each node; for an undirected graph, its degree.
+    balance: Vec<isize>,
+    directed: bool,
+}
+
+impl<E: Copy + PartialEq> Walk<E> {
+    fn new<G>(g: G) -> Self
+        where G: IntoEdgeReferences<EdgeId=E> + NodeIndexable + GraphProp,
+    {
+        let directed = g.is_directed();
+        let mut walk = Walk {
+            edges: Vec::new(),
+            tails: Vec::new(),
+            heads: Vec::new(),
+            adjacent: vec![Vec::new(); g.node_bound()],
+            balance: vec![0; g.node_bound()],
+            directed: directed,
+        };
+        for edge in g.edge_references() {
+            let (a, b) = (g.to_index(edge.source()), g.to_index(edge.target()));
+            let number = walk.edges.len();
+            walk.edges.push(edge.id());
+            walk.tails.push(a);
+            walk.heads.push(b);
+            walk.adjacent[a].push(number);
+            if directed {
+                walk.balance[a] += 1;
+                walk.balance[b] -= 1;
+            } else {
+                walk.balance[a] += 1;
+                walk.balance[b] += 1;
+                if a != b {
+                    walk.adjacent[b].push(number);
+                }
+            }
+        }
+        walk
+    }
+
+    /// Return the nodes that a circuit can't pass through, in the order of
+    /// `node_identifiers`.
+    fn unbalanced<G>(&self, g: G) -> Vec<G::NodeId>
+        where G: IntoNodeIdentifiers + NodeIndexable,
+    {
+        g.node_identifiers().filter(|&n| {
+            let balance = self.balance[g.to_index(n)];
+            if self.directed { balance != 0 } else { balance % 2 != 0 }
+        }).collect()
+    }
+
+    /// Walk the edges from `start`, splicing in detours until no edge is
+    /// left, or return `Disconnected` if some edges can't be reached.
+    fn walk<N>(&self, start: usize) -> Result<Vec<E>, EulerianError<N>> {
+        let mut used = vec![false; self.edges.len()];
+        let mut next = vec![0; self.adjacent.len()];
+        // The walk so far, as the edge that led to each node; the edges are
+        // moved to `circuit` in reverse as their nodes run out of edges.
+        let mut stack: Vec<(usize, Option<usize>)> = vec![(start, None)];
+        let mut circuit = Vec::with_capacity(self.edges.len());
+        while let Some(&(node, arrived_by)) = stack.last() {
+            let out = &self.adjacent[node];
+            while next[node] < out.len() && used[out[next[node]]] {
+                next[node] += 1;
+            }
+            if next[node] < out.len() {
+                let e = out[next[node]];
+                used[e] = true;
+                let other = if self.tails[e] != node { self.tails[e] } else { self.heads[e] };
+                stack.push((other, Some(e)));
+            } else {
+                stack.pop();
+                if let Some(e) = arrived_by {
+                    circuit.push(self.edges[e]);
+                }
+            }
+        }
+        if circuit.len() < self.edges.len() {
+            return Err(EulerianError::Disconnected);
+        }
+        circuit.reverse();
+        Ok(circuit)
+    }
+}
@@ -0,0 +1,153 @@
+use visit::{
+    EdgeRef,
+    GraphProp,
+    IntoEdgeReferences,
+    NodeIndexable,
+};
+
+use super::{
+    negative_cycle,
+    predecessor_cycle,
+    FloatMeasure,
+    NegativeCycle,
+};
+
+/// [Generic] Compute shortest paths between all pairs of nodes.
+///
+/// Using the [Floyd–Warshall algorithm][fw] with runtime **O(|V|³)**;
+/// negative edge costs are permitted, but the graph must not have a cycle of
+/// negative weights (in that case it will return a `NegativeCycle` error
+/// holding such a cycle).
+///
+/// On success, return a distance matrix and a successor matrix, both indexed
+/// by the graph's node indices (`distance[i][j]` is the cost of a shortest
+/// path from node `i` to node `j`). `next[i][j]` is the node that follows `i`
+/// on a shortest path towards `j`, or `None` if `j` is not reachable from `i`
+/// (and when `i == j`). Unreachable pairs have an infinite distance.
+///
+/// For an undirected graph, each edge is traversable in both directions.
+///
+/// [fw]: https://en.wikipedia.org/wiki/Floyd%E2%80%93Warshall_algorithm
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::floyd_warshall;
+/// use petgraph::visit::NodeIndexable;
+///
+/// let mut g = Graph::new();
+/// let a = g.add_node(());
+/// let b = g.add_node(());
+/// let c = g.add_node(());
+/// let d = g.add_node(());
+/// g.extend_with_edges(&[
+///     (a, b, 1.0f64),
+///     (b, c, 2.0),
+///     (a, c, 4.0),
+///     (c, d, -1.0),
+/// ]);
+///
+/// let (distance, next) = floyd_warshall(&g).unwrap();
+/// assert_eq!(distance[a.index()][d.index()], 2.0);
+/// assert!(distance[d.index()][a.index()].is_infinite());
+///
+/// // Follow the successors to recover the path from a to d.
+/// let mut path = vec![a];
+/// let mut node = a;
+/// while let Some(succ) = next[g.to_index(node)][g.to_index(d)] {
+///     path.push(succ);
+///     node = succ;
+/// }
+/// assert_eq!(path, vec![a, b, c, d]);
+/// ```
+pub fn floyd_warshall<G>(g: G)
+    -> Result<(Vec<Vec<G::EdgeWeight>>, Vec<Vec<Option<G::NodeId>>>),
+              NegativeCycle<G::NodeId, G::EdgeId>>
+    where G: IntoEdgeReferences + NodeIndexable + GraphProp,
+          G::EdgeWeight: FloatMeasure,
+{
+    let n = g.node_bound();
+    let mut distance = vec![vec![<_>::infinite(); n]; n];
+    let mut next = vec![vec![None; n]; n];
+
+    for (i, row) in distance.iter_mut().enumerate() {
+        row[i] = <_>::zero();
+    }
+
+    {
+        let mut relax = |a: G::NodeId, b: G::NodeId, w: G::EdgeWeight| {
+            let (i, j) = (g.to_index(a), g.to_index(b));
+            if w < distance[i][j] {
+                distance[i][j] = w;
+                next[i][j] = Some(b);
+            }
+        };
+        for edge in g.edge_references() {
+            let w = *edge.weight();
+            relax(edge.source(), edge.target(), w);
+            if !g.is_directed() {
+                relax(edge.target(), edge.source(), w);
+            }
+        }
+    }
+
+    for k in 0..n {
+        for i in 0..n {
+            let d_ik = distance[i][k];
+            if d_ik == <_>::infinite() {
+                continue;
+            }
+            for j in 0..n {
+                let d = d_ik + distance[k][j];
+                if d < distance[i][j] {
+                    distance[i][j] = d;
+                    next[i][j] = next[i][k];
+                }
+            }
+        }
+    }
+
+    // a negative distance from a node to itself means a negative cycle
+    if (0..n).any(|i| distance[i][i] < <_>::zero()) {
+        if let Some(cycle) = find_negative_cycle(g) {
+            return Err(cycle);
+        }
+    }
+
+    Ok((distance, next))
+}
+
+/// Find a cycle of negative weights by Bellman–Ford relaxation from a virtual
+/// source with a zero-cost edge to every node.
+fn find_negative_cycle<G>(g: G) -> Option<NegativeCycle<G::NodeId, G::EdgeId>>
+    where G: IntoEdgeReferences + NodeIndexable + GraphProp,
+          G::EdgeWeight: FloatMeasure,
+{
+    let mut edges = Vec::new();
+    for edge in g.edge_references() {
+        let (a, b) = (g.to_index(edge.source()), g.to_index(edge.target()));
+        edges.push((a, b, *edge.weight(), edge.id()));
+        if !g.is_directed() {
+            edges.push((b, a, *edge.weight(), edge.id()));
+        }
+    }
+
+    let mut distance = vec![<G::EdgeWeight>::zero(); g.node_bound()];
+    let mut predecessor = vec![None; g.node_bound()];
+    loop {
+        let mut did_update = false;
+        for &(i, j, w, id) in &edges {
+            if distance[i] + w < distance[j] {
+                distance[j] = distance[i] + w;
+                predecessor[j] = Some((i, id));
+                did_update = true;
+            }
+        }
+        if !did_update {
+            return None;
+        }
+        if let Some(cycle) = predecessor_cycle(g.node_bound(), |i| predecessor[i].map(|p| p.0)) {
+            return Some(negative_cycle(g, &cycle, |i| predecessor[i].unwrap().1));
+        }
+    }
+}
@@ -0,0 +1,200 @@
+use std::collections::{
+    HashMap,
+    VecDeque,
+};
+use std::hash::Hash;
+use std::ops::Sub;
+
+use graph::{
+    Graph,
+    NodeIndex,
+};
+use visit::{
+    EdgeRef,
+    GraphProp,
+    IntoEdgeReferences,
+    IntoNodeIdentifiers,
+    NodeIndexable,
+};
+use Undirected;
+
+use super::{
+    max_flow,
+    Measure,
+};
+
+/// [Generic] Compute a Gomory–Hu tree of an undirected graph, which answers
+/// minimum cut queries between every pair of nodes.
+///
+/// The tree has a node for each node of the graph, holding its `NodeId`, and
+/// its edges are weighted such that, for any two nodes, the lightest edge on
+/// the path between them in the tree is a minimum cut between them in the
+/// graph: its weight is the value of the cut, and removing it from the tree
+/// splits the nodes the same way as the cut.
+///
+/// Using [Gusfield's algorithm][gusfield], which builds the tree with
+/// |V| - 1 calls to `max_flow` on the original graph. The function
+/// `capacity` should return the capacity of a particular edge, which must
+/// be non-negative.
+///
+/// [gusfield]: https://en.wikipedia.org/wiki/Gomory%E2%80%93Hu_tree
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::gomory_hu_tree;
+///
+/// let mut g = Graph::new_undirected();
+/// let a = g.add_node("a");
+/// let b = g.add_node("b");
+/// let c = g.add_node("c");
+/// let d = g.add_node("d");
+/// g.extend_with_edges(&[
+///     (a, b, 3),
+///     (b, c, 1),
+///     (c, d, 4),
+///     (a, c, 2),
+/// ]);
+///
+/// let tree = gomory_hu_tree(&g, |e| *e.weight());
+/// assert_eq!(tree.tree().edge_count(), 3);
+/// assert_eq!(tree.min_cut(a, d), Some((3, vec![a, b])));
+/// assert_eq!(tree.min_cut(b, a), Some((4, vec![b])));
+/// assert_eq!(tree.min_cut(d, c).map(|cut| cut.0), Some(4));
+/// ```
+pub fn gomory_hu_tree<G, F, K>(g: G, mut capacity: F) -> GomoryHuTree<G::NodeId, K>
+    where G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable + GraphProp,
+          G::NodeId: Eq + Hash,
+          G::EdgeId: Eq + Hash,
+          F: FnMut(G::EdgeRef) -> K,
+          K: Measure + Copy + Sub<Output=K>,
+{
+    let nodes: Vec<_> = g.node_identifiers().collect();
+    let n = nodes.len();
+
+    // Each node but the first is joined to `parent` by an edge of weight
+    // `value`; the tree is refined by one minimum cut at a time.
+    let mut parent = vec![0; n];
+    let mut value = vec![K::default(); n];
+    let mut on_source_side = vec![false; g.node_bound()];
+    for s in 1..n {
+        let t = parent[s];
+        let flow = max_flow(g, nodes[s], nodes[t], &mut capacity);
+        for side in &mut on_source_side {
+            *side = false;
+        }
+        for &node in flow.min_cut() {
+            on_source_side[g.to_index(node)] = true;
+        }
+        let in_cut = |i: usize| on_source_side[g.to_index(nodes[i])];
+
+        value[s] = flow.value();
+        for (i, p) in parent.iter_mut().enumerate() {
+            if i != s && *p == t && in_cut(i) {
+                *p = s;
+            }
+        }
+        if t != 0 && in_cut(parent[t]) {
+            parent[s] = parent[t];
+            parent[t] = s;
+            value[s] = value[t];
+            value[t] = flow.value();
+        }
+    }
+
+    let mut tree = Graph::with_capacity(n, n.saturating_sub(1));
+    let mut index = HashMap::new();
+    for &node in &nodes {
+        index.insert(node, tree.add_node(node));
+    }
+    for i in 1..n {
+        tree.add_edge(NodeIndex::new(i), NodeIndex::new(parent[i]), value[i]);
+    }
+    GomoryHuTree {
+        tree: tree,
+        index: index,
+    }
+}
+
+/// The result of `gomory_hu_tree`.
+#[derive(Clone, Debug)]
+pub struct GomoryHuTree<N, K>
+    where N: Eq + Hash,
+{
+    tree: Graph<N, K, Undirected>,
+    index: HashMap<N, NodeIndex>,
+}
+
+impl<N, K> GomoryHuTree<N, K>
+    where N: Copy + Eq + Hash,
+          K: PartialOrd + Copy,
+{
+    /// Return the tree, whose node weights are the nodes of the graph and
+    /// whose edge weights are the values of minimum cuts.
+    pub fn tree(&self) -> &Graph<N, K, Undirected> {
+        &self.tree
+    }
+
+    /// Return the tree, consuming `self`.
+    pub fn into_tree(self) -> Graph<N, K, Undirected> {
+        self.tree
+    }
+
+    /// Return the value of a minimum cut between `a` and `b`, and the nodes on
+    /// the side of it that contains `a`, in the order they were in the graph.
+    ///
+    /// Return `None` if `a` and `b` are the same node or either of them is not
+    /// in the tree.
+    pub fn min_cut(&self, a: N, b: N) -> Option<(K, Vec<N>)> {
+        let (start, goal) = match (self.index.get(&a), self.index.get(&b)) {
+            (Some(&start), Some(&goal)) if start != goal => (start, goal),
+            _ => return None,
+        };
+
+        // Find the path from `a` to `b`, and its lightest edge.
+        let mut predecessor = vec![None; self.tree.node_count()];
+        let mut queue = VecDeque::new();
+        queue.push_back(start);
+        while let Some(node) = queue.pop_front() {
+            for edge in self.tree.edges(node) {
+                let next = edge.target();
+                if next != start && predecessor[next.index()].is_none() {
+                    predecessor[next.index()] = Some((node, edge.id()));
+                    queue.push_back(next);
+                }
+            }
+        }
+        let mut lightest = None;
+        let mut node = goal;
+        while let Some((previous, edge)) = predecessor[node.index()] {
+            lightest = match lightest {
+                Some(e) if self.tree[e] <= self.tree[edge] => Some(e),
+                _ => Some(edge),
+            };
+            node = previous;
+        }
+        let cut_edge = match lightest {
+            Some(e) => e,
+            None => return None,
+        };
+
+        // The nodes that `a` still reaches without that edge.
+        let mut seen = vec![false; self.tree.node_count()];
+        let mut stack = vec![start];
+        seen[start.index()] = true;
+        while let Some(node) = stack.pop() {
+            for edge in self.tree.edges(node) {
+                let next = edge.target();
+                if edge.id() != cut_edge && !seen[next.index()] {
+                    seen[next.index()] = true;
+                    stack.push(next);
+                }
+            }
+        }
+        let side = self.tree.node_indices()
+                            .filter(|n| seen[n.index()])
+                            .map(|n| self.tree[n])
+                            .collect();
+        Some((self.tree[cut_edge], side))
+    }
+}
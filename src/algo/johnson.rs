@@ -0,0 +1,86 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::Sub;
+
+use visit::{
+    EdgeRef,
+    IntoEdges,
+    IntoNodeIdentifiers,
+    NodeCount,
+    NodeIndexable,
+    Visitable,
+};
+
+use super::{
+    bellman_ford_relax,
+    dijkstra,
+    FloatMeasure,
+    NegativeCycle,
+};
+
+/// [Generic] Compute shortest paths between all pairs of nodes in a sparse
+/// graph.
+///
+/// Using [Johnson's algorithm][johnson]; negative edge costs are permitted,
+/// but the graph must not have a cycle of negative weights (in that case it
+/// will return a `NegativeCycle` error holding such a cycle).
+///
+/// The edge costs are first reweighted to be non-negative, using node
+/// potentials computed by the Bellman–Ford algorithm, and then Dijkstra's
+/// algorithm is run from every node. The runtime is **O(|V|·|E| log |V|)**,
+/// which is better than `floyd_warshall` for sparse graphs.
+///
+/// On success, return a map from each source node to a map of path costs to
+/// every node reachable from it. Unreachable nodes are not included.
+///
+/// [johnson]: https://en.wikipedia.org/wiki/Johnson%27s_algorithm
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::johnson;
+///
+/// let mut g = Graph::new();
+/// let a = g.add_node(());
+/// let b = g.add_node(());
+/// let c = g.add_node(());
+/// let d = g.add_node(());
+/// g.extend_with_edges(&[
+///     (a, b, 3.0),
+///     (b, c, -2.0),
+///     (a, c, 2.0),
+///     (c, d, 1.0),
+/// ]);
+///
+/// let distances = johnson(&g).unwrap();
+/// assert_eq!(distances[&a][&c], 1.0);
+/// assert_eq!(distances[&a][&d], 2.0);
+/// assert_eq!(distances[&b][&d], -1.0);
+/// assert!(!distances[&d].contains_key(&a));
+/// ```
+pub fn johnson<G>(g: G)
+    -> Result<HashMap<G::NodeId, HashMap<G::NodeId, G::EdgeWeight>>,
+              NegativeCycle<G::NodeId, G::EdgeId>>
+    where G: NodeCount + IntoNodeIdentifiers + IntoEdges + NodeIndexable + Visitable,
+          G::NodeId: Eq + Hash,
+          G::EdgeWeight: FloatMeasure + Sub<Output=G::EdgeWeight>,
+{
+    // Potentials are the distances from a virtual source that has a zero-cost
+    // edge to every node.
+    let mut potential = vec![<_>::zero(); g.node_bound()];
+    let mut predecessor = vec![None; g.node_bound()];
+    bellman_ford_relax(g, &mut potential, &mut predecessor)?;
+
+    let h = |n| potential[g.to_index(n)];
+    let mut distances = HashMap::new();
+    for source in g.node_identifiers() {
+        let scores = dijkstra(g, source, None, |edge| {
+            *edge.weight() + h(edge.source()) - h(edge.target())
+        });
+        let scores = scores.into_iter()
+                           .map(|(n, s)| (n, s - h(source) + h(n)))
+                           .collect();
+        distances.insert(source, scores);
+    }
+    Ok(distances)
+}
@@ -0,0 +1,133 @@
+use std::collections::{
+    BinaryHeap,
+    HashSet,
+};
+use std::hash::Hash;
+
+use scored::MinScored;
+use visit::{
+    EdgeFiltered,
+    EdgeRef,
+    IntoEdges,
+    VisitMap,
+    Visitable,
+};
+
+use super::{
+    dijkstra_paths,
+    Measure,
+};
+
+/// [Generic] Yen's algorithm for the *k* shortest loopless paths.
+///
+/// Compute up to `k` shortest paths from `start` to `goal` that do not
+/// visit any node more than once, and return them in nondecreasing order of
+/// their total cost. Fewer than `k` paths are returned if there are no more
+/// loopless paths between the two nodes.
+///
+/// Paths are compared by their sequence of nodes; where there are parallel
+/// edges, only the cheapest of them is used.
+///
+/// The graph should be `Visitable` and implement `IntoEdges`. The function
+/// `edge_cost` should return the cost for a particular edge. Edge costs must
+/// be non-negative.
+///
+/// Returns a vector of the total cost + the path of subsequent `NodeId` from
+/// `start` to `goal`, for each path that was found.
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::k_shortest_paths;
+///
+/// let mut g = Graph::new();
+/// let a = g.add_node(());
+/// let b = g.add_node(());
+/// let c = g.add_node(());
+/// let d = g.add_node(());
+/// g.extend_with_edges(&[
+///     (a, b, 1),
+///     (b, d, 1),
+///     (a, c, 1),
+///     (c, d, 2),
+///     (b, c, 1),
+/// ]);
+///
+/// let paths = k_shortest_paths(&g, a, d, 5, |e| *e.weight());
+/// assert_eq!(paths, vec![
+///     (2, vec![a, b, d]),
+///     (3, vec![a, c, d]),
+///     (4, vec![a, b, c, d]),
+/// ]);
+/// ```
+pub fn k_shortest_paths<G, F, K>(graph: G, start: G::NodeId, goal: G::NodeId, k: usize,
+                                 mut edge_cost: F)
+    -> Vec<(K, Vec<G::NodeId>)>
+    where G: IntoEdges + Visitable,
+          G::NodeId: Eq + Hash,
+          F: FnMut(G::EdgeRef) -> K,
+          K: Measure + Copy,
+{
+    if k == 0 {
+        return Vec::new();
+    }
+
+    // Each path is kept as its nodes and the cost of reaching each of them.
+    let mut shortest: Vec<(Vec<G::NodeId>, Vec<K>)> = Vec::new();
+    let mut candidates = BinaryHeap::new();
+    let mut seen = HashSet::new();
+    let first = dijkstra_paths(graph, start, Some(goal), &mut edge_cost);
+    if let Some(path) = first.path_to(goal) {
+        let costs = path.iter().map(|n| first.scores()[n]).collect();
+        seen.insert(path.clone());
+        shortest.push((path, costs));
+    }
+
+    while !shortest.is_empty() && shortest.len() < k {
+        {
+            let (ref last, ref last_costs) = shortest[shortest.len() - 1];
+            for i in 0..last.len() - 1 {
+                let spur = last[i];
+                let root = &last[..i + 1];
+
+                // Don't revisit the root path, and don't repeat the next step
+                // of any shortest path that shares the same root.
+                let mut blocked = graph.visit_map();
+                for &n in &root[..i] {
+                    blocked.visit(n);
+                }
+                let blocked_next: Vec<_> = shortest.iter()
+                    .map(|p| &p.0)
+                    .filter(|p| p.len() > i + 1 && &p[..i + 1] == root)
+                    .map(|p| p[i + 1])
+                    .collect();
+                let filtered = EdgeFiltered::from_fn(graph, |e: G::EdgeRef| {
+                    let next = e.target();
+                    !(blocked.is_visited(&next) ||
+                      (e.source() == spur && blocked_next.contains(&next)))
+                });
+
+                let spur_paths = dijkstra_paths(&filtered, spur, Some(goal), &mut edge_cost);
+                if let Some(spur_path) = spur_paths.path_to(goal) {
+                    let mut path = root.to_vec();
+                    let mut costs = last_costs[..i + 1].to_vec();
+                    for n in &spur_path[1..] {
+                        path.push(*n);
+                        costs.push(last_costs[i] + spur_paths.scores()[n]);
+                    }
+                    if seen.insert(path.clone()) {
+                        candidates.push(MinScored(costs[costs.len() - 1], (path, costs)));
+                    }
+                }
+            }
+        }
+        match candidates.pop() {
+            Some(MinScored(_, next)) => shortest.push(next),
+            None => break,
+        }
+    }
+
+    shortest.into_iter()
+            .map(|(path, costs)| (costs[costs.len() - 1], path))
+            .collect()
+}
@@ -0,0 +1,628 @@
+use std::collections::VecDeque;
+use std::ops::Sub;
+
+use visit::{
+    EdgeRef,
+    GraphBase,
+    IntoEdgeReferences,
+    IntoEdges,
+    IntoNodeIdentifiers,
+    NodeIndexable,
+};
+
+use super::Measure;
+
+/// [Generic] Compute a maximum matching of a bipartite graph.
+///
+/// A matching is a set of edges that have no endpoint in common; a maximum
+/// matching has as many edges as possible. The nodes of the graph are split
+/// into the given `left` nodes and the rest, and only the edges from a left
+/// node to a node that is not in `left` are used. In a directed graph, only the
+/// outgoing edges of the left nodes are used.
+///
+/// Using the [Hopcroft–Karp algorithm][hk] with runtime
+/// **O(|E|·√|V|)**.
+///
+/// Return the edges of the matching, in the order of the left nodes they
+/// belong to.
+///
+/// [hk]: https://en.wikipedia.org/wiki/Hopcroft%E2%80%93Karp_algorithm
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::maximum_bipartite_matching;
+///
+/// // Workers and the tasks that they can do.
+/// let mut g = Graph::<&str, ()>::new();
+/// let alice = g.add_node("alice");
+/// let bob = g.add_node("bob");
+/// let carol = g.add_node("carol");
+/// let build = g.add_node("build");
+/// let test = g.add_node("test");
+/// let deploy = g.add_node("deploy");
+/// let ab = g.add_edge(alice, build, ());
+/// g.add_edge(bob, build, ());
+/// let bt = g.add_edge(bob, test, ());
+/// let cd = g.add_edge(carol, deploy, ());
+/// g.add_edge(carol, test, ());
+///
+/// let matching = maximum_bipartite_matching(&g, vec![alice, bob, carol]);
+/// assert_eq!(matching, vec![ab, bt, cd]);
+/// ```
+pub fn maximum_bipartite_matching<G, I>(g: G, left: I) -> Vec<G::EdgeId>
+    where G: IntoEdges + NodeIndexable,
+          I: IntoIterator<Item=G::NodeId>,
+{
+    let mut is_left = vec![false; g.node_bound()];
+    let mut left_nodes = Vec::new();
+    for node in left {
+        let i = g.to_index(node);
+        if !is_left[i] {
+            is_left[i] = true;
+            left_nodes.push(node);
+        }
+    }
+
+    // The right nodes (by node index) and edges adjacent to each left node.
+    let adjacent: Vec<Vec<(usize, G::EdgeId)>> = left_nodes.iter().map(|&node| {
+        g.edges(node).filter_map(|edge| {
+            let other = if edge.source() != node { edge.source() } else { edge.target() };
+            let j = g.to_index(other);
+            if is_left[j] { None } else { Some((j, edge.id())) }
+        }).collect()
+    }).collect();
+
+    let n = left_nodes.len();
+    let mut mate_left: Vec<Option<(usize, G::EdgeId)>> = vec![None; n];
+    let mut mate_right: Vec<Option<usize>> = vec![None; g.node_bound()];
+    let mut layer = vec![None; n];
+    let mut next_edge = vec![0; n];
+
+    loop {
+        // Layer the left nodes by their distance from a free left node along
+        // alternating paths, up to the first layer that reaches a free right
+        // node.
+        let mut queue = VecDeque::new();
+        for u in 0..n {
+            if mate_left[u].is_none() {
+                layer[u] = Some(0);
+                queue.push_back(u);
+            } else {
+                layer[u] = None;
+            }
+        }
+        let mut free_layer = None;
+        while let Some(u) = queue.pop_front() {
+            let d = layer[u].unwrap();
+            match free_layer {
+                Some(f) if d >= f => break,
+                _ => {}
+            }
+            for &(v, _) in &adjacent[u] {
+                match mate_right[v] {
+                    None => free_layer = Some(d + 1),
+                    Some(w) => if layer[w].is_none() {
+                        layer[w] = Some(d + 1);
+                        queue.push_back(w);
+                    },
+                }
+            }
+        }
+        if free_layer.is_none() {
+            break;
+        }
+
+        // Find a maximal set of disjoint shortest augmenting paths.
+        for x in &mut next_edge {
+            *x = 0;
+        }
+        for root in 0..n {
+            if mate_left[root].is_some() {
+                continue;
+            }
+            let mut path = vec![root];
+            while let Some(&u) = path.last() {
+                if next_edge[u] == adjacent[u].len() {
+                    // A dead end: don't come back here in this phase.
+                    layer[u] = None;
+                    path.pop();
+                    if let Some(&p) = path.last() {
+                        next_edge[p] += 1;
+                    }
+                    continue;
+                }
+                let (v, _) = adjacent[u][next_edge[u]];
+                let next_layer = layer[u].map(|d| d + 1);
+                match mate_right[v] {
+                    None if next_layer == free_layer => {
+                        // Flip the matching along the path.
+                        for &u in &path {
+                            let (v, e) = adjacent[u][next_edge[u]];
+                            mate_left[u] = Some((v, e));
+                            mate_right[v] = Some(u);
+                        }
+                        break;
+                    }
+                    Some(w) if next_layer.is_some() && layer[w] == next_layer => {
+                        path.push(w);
+                    }
+                    _ => next_edge[u] += 1,
+                }
+            }
+        }
+    }
+
+    mate_left.into_iter().filter_map(|m| m.map(|(_, e)| e)).collect()
+}
+
+/// [Generic] Compute a minimum cost matching of a weighted bipartite graph.
+///
+/// The nodes of the graph are split into the given `left` nodes and the
+/// rest, and only the edges from a left node to a node that is not in `left`
+/// are used, as in `maximum_bipartite_matching`; the right side is made of
+/// the other nodes that such an edge reaches. Every node of the smaller side
+/// is matched, and nodes of the larger side are left unmatched as
+/// needed; when both sides have the same size, this is a minimum cost perfect
+/// matching.
+///
+/// The function `edge_cost` should return the cost for a particular edge.
+/// Costs may be negative. Of several parallel edges, the cheapest is used.
+///
+/// Using the [Hungarian algorithm][km] (Kuhn–Munkres) with runtime
+/// **O(|V|³)**.
+///
+/// Return the total cost and the edges of the matching, in the order of the
+/// left nodes they belong to, or `None` if the smaller side can't be
+/// matched completely.
+///
+/// [km]: https://en.wikipedia.org/wiki/Hungarian_algorithm
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::kuhn_munkres;
+///
+/// // Three workers and the cost for each of them to do one of two tasks.
+/// let mut g = Graph::new();
+/// let alice = g.add_node("alice");
+/// let bob = g.add_node("bob");
+/// let carol = g.add_node("carol");
+/// let build = g.add_node("build");
+/// let test = g.add_node("test");
+/// g.add_edge(alice, build, 4);
+/// let at = g.add_edge(alice, test, 2);
+/// g.add_edge(bob, build, 3);
+/// g.add_edge(bob, test, 5);
+/// let cb = g.add_edge(carol, build, 1);
+/// g.add_edge(carol, test, 3);
+///
+/// let (cost, matching) = kuhn_munkres(&g, vec![alice, bob, carol], |e| *e.weight()).unwrap();
+/// assert_eq!(cost, 3);
+/// assert_eq!(matching, vec![at, cb]);
+/// ```
+pub fn kuhn_munkres<G, I, F, K>(g: G, left: I, mut edge_cost: F)
+    -> Option<(K, Vec<G::EdgeId>)>
+    where G: IntoEdges + NodeIndexable,
+          I: IntoIterator<Item=G::NodeId>,
+          F: FnMut(G::EdgeRef) -> K,
+          K: Measure + Copy + Sub<Output=K>,
+{
+    let mut is_left = vec![false; g.node_bound()];
+    let mut left_nodes = Vec::new();
+    for node in left {
+        let i = g.to_index(node);
+        if !is_left[i] {
+            is_left[i] = true;
+            left_nodes.push(node);
+        }
+    }
+
+    // The cheapest edge between each left node and each right node, with the
+    // right nodes numbered in the order they are found.
+    let mut right_position = vec![None; g.node_bound()];
+    let mut n_right = 0;
+    let mut edges: Vec<Vec<Option<(K, G::EdgeId)>>> = Vec::with_capacity(left_nodes.len());
+    for &node in &left_nodes {
+        let mut row = vec![None; n_right];
+        for edge in g.edges(node) {
+            let other = if edge.source() != node { edge.source() } else { edge.target() };
+            let j = g.to_index(other);
+            if is_left[j] {
+                continue;
+            }
+            let position = match right_position[j] {
+                Some(p) => p,
+                None => {
+                    right_position[j] = Some(n_right);
+                    n_right += 1;
+                    n_right - 1
+                }
+            };
+            if row.len() <= position {
+                row.resize(position + 1, None);
+            }
+            let cost = edge_cost(edge);
+            row[position] = match row[position] {
+                Some((c, e)) if c <= cost => Some((c, e)),
+                _ => Some((cost, edge.id())),
+            };
+        }
+        edges.push(row);
+    }
+    let n_left = left_nodes.len();
+    for row in &mut edges {
+        row.resize(n_right, None);
+    }
+
+    // Match each node of the smaller side in turn.
+    let transpose = n_left > n_right;
+    let (n_rows, n_cols) = if transpose { (n_right, n_left) } else { (n_left, n_right) };
+    let cell = |i: usize, j: usize| if transpose { edges[j][i] } else { edges[i][j] };
+    let col_of_row = assign(n_rows, n_cols, |i, j| cell(i, j).map(|(c, _)| c));
+    let col_of_row = match col_of_row {
+        Some(c) => c,
+        None => return None,
+    };
+
+    let mut cost = K::default();
+    let mut matched = Vec::with_capacity(n_rows);
+    for (i, &j) in col_of_row.iter().enumerate() {
+        let (c, e) = cell(i, j).unwrap();
+        cost = cost + c;
+        matched.push((if transpose { j } else { i }, e));
+    }
+    matched.sort_by_key(|&(l, _)| l);
+    Some((cost, matched.into_iter().map(|(_, e)| e).collect()))
+}
+
+/// Assign a distinct column to each of `n_rows` rows, where `n_rows <= n_cols`,
+/// minimizing the total cost. `cost(i, j)` is the cost of assigning column
+/// `j` to row `i`, or `None` if it is not allowed.
+///
+/// Return the column of each row, or `None` if there is no such assignment.
+fn assign<F, K>(n_rows: usize, n_cols: usize, cost: F) -> Option<Vec<usize>>
+    where F: Fn(usize, usize) -> Option<K>,
+          K: Measure + Copy + Sub<Output=K>,
+{
+    let zero = K::default();
+    // The potentials keep `cost(i, j) + col_potential[j] - row_potential[i]`
+    // non-negative, and zero for the assigned pairs. Column `n_cols` is a
+    // virtual column that the row being added starts from.
+    let mut row_potential = vec![zero; n_rows];
+    let mut col_potential = vec![zero; n_cols + 1];
+    let mut row_of_col: Vec<Option<usize>> = vec![None; n_cols + 1];
+    let mut previous_col = vec![0; n_cols + 1];
+    let mut min_reduced: Vec<Option<K>> = vec![None; n_cols + 1];
+    let mut used = vec![false; n_cols + 1];
+
+    for row in 0..n_rows {
+        // Grow a tree of alternating paths from `row`, Dijkstra style, until
+        // it reaches a free column.
+        row_of_col[n_cols] = Some(row);
+        for j in 0..n_cols + 1 {
+            min_reduced[j] = None;
+            used[j] = false;
+        }
+        let mut col = n_cols;
+        loop {
+            used[col] = true;
+            let i = row_of_col[col].unwrap();
+            for j in 0..n_cols {
+                if used[j] {
+                    continue;
+                }
+                if let Some(c) = cost(i, j) {
+                    let reduced = c + col_potential[j] - row_potential[i];
+                    let improves = match min_reduced[j] {
+                        Some(m) => reduced < m,
+                        None => true,
+                    };
+                    if improves {
+                        min_reduced[j] = Some(reduced);
+                        previous_col[j] = col;
+                    }
+                }
+            }
+
+            let mut next = None;
+            for j in 0..n_cols {
+                if let (false, Some(m)) = (used[j], min_reduced[j]) {
+                    next = match next {
+                        Some((_, best)) if best <= m => next,
+                        _ => Some((j, m)),
+                    };
+                }
+            }
+            let (next_col, delta) = match next {
+                Some(n) => n,
+                None => return None,
+            };
+            for j in 0..n_cols + 1 {
+                if used[j] {
+                    let r = row_of_col[j].unwrap();
+                    row_potential[r] = row_potential[r] + delta;
+                    col_potential[j] = col_potential[j] + delta;
+                } else if let Some(m) = min_reduced[j] {
+                    min_reduced[j] = Some(m - delta);
+                }
+            }
+            col = next_col;
+            if row_of_col[col].is_none() {
+                break;
+            }
+        }
+
+        // Shift the assignment along the path back to the virtual column.
+        while col != n_cols {
+            let previous = previous_col[col];
+            row_of_col[col] = row_of_col[previous];
+            col = previous;
+        }
+    }
+
+    let mut col_of_row = vec![0; n_rows];
+    for (j, row) in row_of_col[..n_cols].iter().enumerate() {
+        if let Some(i) = *row {
+            col_of_row[i] = j;
+        }
+    }
+    Some(col_of_row)
+}
+
+/// [Generic] Compute a maximum matching of a general graph.
+///
+/// A matching is a set of edges that have no endpoint in common; a maximum
+/// matching has as many edges as possible. Unlike
+/// `maximum_bipartite_matching`, the graph can have odd cycles. Edge
+/// directions are ignored, and self loops are never matched.
+///
+/// Using [Edmonds' blossom algorithm][blossom] with runtime **O(|V|³)**.
+///
+/// [blossom]: https://en.wikipedia.org/wiki/Blossom_algorithm
+///
+/// # Example
+/// ```rust
+/// use petgraph::graph::UnGraph;
+/// use petgraph::algo::maximum_matching;
+///
+/// // A triangle with a tail on each corner: no bipartite split exists.
+/// let mut g = UnGraph::<(), ()>::new_undirected();
+/// let a = g.add_node(());
+/// let b = g.add_node(());
+/// let c = g.add_node(());
+/// let x = g.add_node(());
+/// let y = g.add_node(());
+/// let z = g.add_node(());
+/// g.extend_with_edges(&[(a, b), (b, c), (c, a), (a, x), (b, y), (c, z)]);
+///
+/// let matching = maximum_matching(&g);
+/// assert_eq!(matching.len(), 3);
+/// assert!(matching.is_perfect());
+/// assert_eq!(matching.mate(x), Some(a));
+/// assert_eq!(matching.mate(c), Some(z));
+/// assert_eq!(matching.edges().count(), 3);
+/// ```
+pub fn maximum_matching<G>(g: G) -> Matching<G>
+    where G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+{
+    let n = g.node_bound();
+    let mut adjacent = vec![Vec::new(); n];
+    for edge in g.edge_references() {
+        let (a, b) = (g.to_index(edge.source()), g.to_index(edge.target()));
+        if a != b {
+            adjacent[a].push((b, edge.id()));
+            adjacent[b].push((a, edge.id()));
+        }
+    }
+
+    let mut blossom = Blossom {
+        adjacent: &adjacent,
+        mate: vec![None; n],
+        parent: vec![None; n],
+        base: (0..n).collect(),
+        in_tree: vec![false; n],
+        in_blossom: vec![false; n],
+    };
+    for root in 0..n {
+        if blossom.mate[root].is_some() {
+            continue;
+        }
+        // Flip the matching along the augmenting path, if there is one.
+        let mut node = blossom.augmenting_path(root);
+        while let Some(v) = node {
+            let p = blossom.parent[v].unwrap();
+            node = blossom.mate[p];
+            blossom.mate[v] = Some(p);
+            blossom.mate[p] = Some(v);
+        }
+    }
+
+    let mut len = 0;
+    let mate = blossom.mate.iter().enumerate().map(|(i, m)| {
+        m.map(|j| {
+            if i < j {
+                len += 1;
+            }
+            let e = adjacent[i].iter().find(|&&(k, _)| k == j).unwrap().1;
+            (g.from_index(j), e)
+        })
+    }).collect();
+    Matching {
+        graph: g,
+        mate: mate,
+        len: len,
+        node_count: g.node_identifiers().count(),
+    }
+}
+
+/// The state of the blossom algorithm, on node indices.
+struct Blossom<'a, E: 'a> {
+    adjacent: &'a [Vec<(usize, E)>],
+    mate: Vec<Option<usize>>,
+    /// The predecessor of each outer node's mate in the alternating tree.
+    parent: Vec<Option<usize>>,
+    /// The base of the blossom that contains each node.
+    base: Vec<usize>,
+    in_tree: Vec<bool>,
+    in_blossom: Vec<bool>,
+}
+
+impl<'a, E> Blossom<'a, E> {
+    /// Grow an alternating tree from `root`, contracting blossoms as they
+    /// are found, and return the free node at the end of an augmenting path.
+    fn augmenting_path(&mut self, root: usize) -> Option<usize> {
+        let n = self.mate.len();
+        for i in 0..n {
+            self.parent[i] = None;
+            self.base[i] = i;
+            self.in_tree[i] = false;
+        }
+        self.in_tree[root] = true;
+        let mut queue = VecDeque::new();
+        queue.push_back(root);
+        while let Some(v) = queue.pop_front() {
+            for &(w, _) in &self.adjacent[v] {
+                if self.base[v] == self.base[w] || self.mate[v] == Some(w) {
+                    continue;
+                }
+                let w_outer = match self.mate[w] {
+                    Some(m) => self.parent[m].is_some(),
+                    None => w == root,
+                };
+                if w_outer {
+                    // An odd cycle: contract the blossom.
+                    let base = self.common_ancestor(v, w);
+                    for x in &mut self.in_blossom {
+                        *x = false;
+                    }
+                    self.mark_path(v, base, w);
+                    self.mark_path(w, base, v);
+                    for i in 0..n {
+                        if self.in_blossom[self.base[i]] {
+                            self.base[i] = base;
+                            if !self.in_tree[i] {
+                                self.in_tree[i] = true;
+                                queue.push_back(i);
+                            }
+                        }
+                    }
+                } else if self.parent[w].is_none() {
+                    self.parent[w] = Some(v);
+                    match self.mate[w] {
+                        None => return Some(w),
+                        Some(m) => {
+                            self.in_tree[m] = true;
+                            queue.push_back(m);
+                        }
+                    }
+                }
+            }
+        }
+        None
+    }
+
+    /// Return the base of the nearest common ancestor of `a` and `b` in the
+    /// alternating tree.
+    fn common_ancestor(&self, mut a: usize, mut b: usize) -> usize {
+        let mut on_path = vec![false; self.mate.len()];
+        loop {
+            a = self.base[a];
+            on_path[a] = true;
+            match self.mate[a] {
+                Some(m) => a = self.parent[m].unwrap(),
+                None => break,
+            }
+        }
+        loop {
+            b = self.base[b];
+            if on_path[b] {
+                return b;
+            }
+            b = self.parent[self.mate[b].unwrap()].unwrap();
+        }
+    }
+
+    /// Mark the blossoms on the path from `v` down to `base`, and point the
+    /// parents along it back towards `child`, so the blossom can be traversed
+    /// either way round.
+    fn mark_path(&mut self, mut v: usize, base: usize, mut child: usize) {
+        while self.base[v] != base {
+            let m = self.mate[v].unwrap();
+            self.in_blossom[self.base[v]] = true;
+            self.in_blossom[self.base[m]] = true;
+            self.parent[v] = Some(child);
+            child = m;
+            v = self.parent[m].unwrap();
+        }
+    }
+}
+
+/// The result of `maximum_matching`: a set of edges with no endpoint in common.
+#[derive(Clone)]
+pub struct Matching<G: GraphBase> {
+    graph: G,
+    mate: Vec<Option<(G::NodeId, G::EdgeId)>>,
+    len: usize,
+    node_count: usize,
+}
+
+impl<G> Matching<G>
+    where G: NodeIndexable + Copy,
+{
+    /// Return the node that `node` is matched with, or `None` if it is not
+    /// matched.
+    pub fn mate(&self, node: G::NodeId) -> Option<G::NodeId> {
+        self.mate.get(self.graph.to_index(node)).and_then(|m| m.map(|(n, _)| n))
+    }
+
+    /// Return an iterator over the edges of the matching.
+    pub fn edges<'a>(&'a self) -> MatchedEdges<'a, G> {
+        MatchedEdges {
+            graph: self.graph,
+            mate: &self.mate,
+            index: 0,
+        }
+    }
+
+    /// Return the number of edges in the matching.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Return `true` if there are no edges in the matching.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Return `true` if every node of the graph is matched.
+    pub fn is_perfect(&self) -> bool {
+        2 * self.len == self.node_count
+    }
+}
+
+/// An iterator over the edges of a `Matching`.
+pub struct MatchedEdges<'a, G: 'a + GraphBase> {
+    graph: G,
+    mate: &'a [Option<(G::NodeId, G::EdgeId)>],
+    index: usize,
+}
+
+impl<'a, G> Iterator for MatchedEdges<'a, G>
+    where G: NodeIndexable,
+{
+    type Item = G::EdgeId;
+    fn next(&mut self) -> Option<G::EdgeId> {
+        while self.index < self.mate.len() {
+            let i = self.index;
+            self.index += 1;
+            if let Some((n, e)) = self.mate[i] {
+                // Each edge is stored at both of its endpoints.
+                if i < self.graph.to_index(n) {
+                    return Some(e);
+                }
+            }
+        }
+        None
+    }
+}
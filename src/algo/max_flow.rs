@@ -0,0 +1,248 @@
+use std::collections::{
+    HashMap,
+    VecDeque,
+};
+use std::hash::Hash;
+use std::ops::Sub;
+
+use visit::{
+    EdgeRef,
+    GraphProp,
+    IntoEdgeReferences,
+    NodeIndexable,
+};
+
+use super::Measure;
+
+/// [Generic] Compute a maximum flow from `source` to `sink`.
+///
+/// Using [Dinic's algorithm][dinic] with runtime **O(|V|²·|E|)**. The
+/// function `capacity` should return the capacity of a particular edge, which
+/// must be non-negative. In a directed graph, flow can only go from an edge's
+/// source to its target; in an undirected graph, each edge can carry flow in
+/// either direction.
+///
+/// Return a `MaxFlow` holding the value of the flow, the flow on each edge and
+/// the source side of a minimum cut.
+///
+/// **Panics** if `source` and `sink` are the same node.
+///
+/// [dinic]: https://en.wikipedia.org/wiki/Dinic%27s_algorithm
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::max_flow;
+///
+/// let mut g = Graph::new();
+/// let s = g.add_node("s");
+/// let a = g.add_node("a");
+/// let b = g.add_node("b");
+/// let t = g.add_node("t");
+/// let sa = g.add_edge(s, a, 3);
+/// let sb = g.add_edge(s, b, 2);
+/// let ab = g.add_edge(a, b, 1);
+/// let at = g.add_edge(a, t, 2);
+/// let bt = g.add_edge(b, t, 3);
+///
+/// let flow = max_flow(&g, s, t, |e| *e.weight());
+/// assert_eq!(flow.value(), 5);
+/// assert_eq!(flow.flow(sa), Some(3));
+/// assert_eq!(flow.flow(ab), Some(1));
+/// assert_eq!(flow.flow(at), Some(2));
+/// assert_eq!(flow.flow(sb), Some(2));
+/// assert_eq!(flow.flow(bt), Some(3));
+/// assert_eq!(flow.min_cut(), &[s]);
+/// ```
+pub fn max_flow<G, F, K>(g: G, source: G::NodeId, sink: G::NodeId, mut capacity: F)
+    -> MaxFlow<G::NodeId, G::EdgeId, K>
+    where G: IntoEdgeReferences + NodeIndexable + GraphProp,
+          G::EdgeId: Eq + Hash,
+          F: FnMut(G::EdgeRef) -> K,
+          K: Measure + Copy + Sub<Output=K>,
+{
+    let (s, t) = (g.to_index(source), g.to_index(sink));
+    assert!(s != t, "max_flow: source and sink must be different nodes");
+
+    let mut network = Network::new(g.node_bound());
+    let mut edges = Vec::new();
+    for edge in g.edge_references() {
+        let cap = capacity(edge);
+        let reverse_cap = if g.is_directed() { K::default() } else { cap };
+        network.add_arc(g.to_index(edge.source()), g.to_index(edge.target()), cap, reverse_cap);
+        edges.push((edge.id(), cap));
+    }
+
+    let mut value = K::default();
+    while network.build_levels(s, t) {
+        network.blocking_flow(s, t, &mut value);
+    }
+
+    // The arcs of each edge were added in order, forward arc first.
+    let flows = edges.into_iter().enumerate().map(|(i, (id, cap))| {
+        let residual = network.residual[2 * i];
+        let flow = if residual <= cap { cap - residual } else { residual - cap };
+        (id, flow)
+    }).collect();
+    let min_cut = (0..g.node_bound())
+        .filter(|&i| network.level[i].is_some())
+        .map(|i| g.from_index(i))
+        .collect();
+
+    MaxFlow {
+        value: value,
+        flows: flows,
+        min_cut: min_cut,
+    }
+}
+
+/// The result of `max_flow`.
+#[derive(Clone, Debug)]
+pub struct MaxFlow<N, E, K>
+    where E: Eq + Hash,
+{
+    value: K,
+    flows: HashMap<E, K>,
+    min_cut: Vec<N>,
+}
+
+impl<N, E, K> MaxFlow<N, E, K>
+    where E: Eq + Hash,
+          K: Copy,
+{
+    /// Return the value of the flow, which is also the capacity of a minimum
+    /// cut.
+    pub fn value(&self) -> K {
+        self.value
+    }
+
+    /// Return the flow on `edge`, or `None` if it is not an edge of the graph.
+    ///
+    /// For an undirected graph, this is the amount of flow on the edge in
+    /// either direction.
+    pub fn flow(&self, edge: E) -> Option<K> {
+        self.flows.get(&edge).cloned()
+    }
+
+    /// Return the map of flows, which has an entry for each edge.
+    pub fn flows(&self) -> &HashMap<E, K> {
+        &self.flows
+    }
+
+    /// Return the nodes on the source side of a minimum cut, in order of
+    /// their node indices.
+    ///
+    /// These are the nodes that can still be reached from the source through
+    /// edges that are not saturated by the flow. Every edge from them to the
+    /// other nodes is saturated, and their capacities add up to the value of
+    /// the flow.
+    pub fn min_cut(&self) -> &[N] {
+        &self.min_cut
+    }
+}
+
+/// A residual network indexed by node index. Arcs are stored in pairs, so
+/// that the reverse of arc `a` is `a ^ 1`.
+struct Network<K> {
+    adjacent: Vec<Vec<usize>>,
+    head: Vec<usize>,
+    residual: Vec<K>,
+    level: Vec<Option<usize>>,
+}
+
+impl<K> Network<K>
+    where K: Measure + Copy + Sub<Output=K>,
+{
+    fn new(node_bound: usize) -> Self {
+        Network {
+            adjacent: vec![Vec::new(); node_bound],
+            head: Vec::new(),
+            residual: Vec::new(),
+            level: vec![None; node_bound],
+        }
+    }
+
+    fn add_arc(&mut self, a: usize, b: usize, cap: K, reverse_cap: K) {
+        self.adjacent[a].push(self.head.len());
+        self.head.push(b);
+        self.residual.push(cap);
+        self.adjacent[b].push(self.head.len());
+        self.head.push(a);
+        self.residual.push(reverse_cap);
+    }
+
+    /// Label each node with its distance from `s` through arcs with residual
+    /// capacity, and return `true` if `t` is reachable.
+    fn build_levels(&mut self, s: usize, t: usize) -> bool {
+        for level in &mut self.level {
+            *level = None;
+        }
+        self.level[s] = Some(0);
+        let mut queue = VecDeque::new();
+        queue.push_back(s);
+        while let Some(a) = queue.pop_front() {
+            let next_level = self.level[a].map(|l| l + 1);
+            for &arc in &self.adjacent[a] {
+                let b = self.head[arc];
+                if self.level[b].is_none() && self.residual[arc] > K::default() {
+                    self.level[b] = next_level;
+                    queue.push_back(b);
+                }
+            }
+        }
+        self.level[t].is_some()
+    }
+
+    /// Saturate every shortest path from `s` to `t`, adding the flow that was
+    /// pushed to `value`.
+    fn blocking_flow(&mut self, s: usize, t: usize, value: &mut K) {
+        // The next arc to try from each node; earlier arcs are known to be
+        // saturated or to lead to a dead end.
+        let mut next_arc = vec![0; self.adjacent.len()];
+        let mut path: Vec<usize> = Vec::new();
+        let mut node = s;
+        loop {
+            if node == t {
+                let mut bottleneck = self.residual[path[0]];
+                for &arc in &path[1..] {
+                    if self.residual[arc] < bottleneck {
+                        bottleneck = self.residual[arc];
+                    }
+                }
+                for &arc in &path {
+                    self.residual[arc] = self.residual[arc] - bottleneck;
+                    self.residual[arc ^ 1] = self.residual[arc ^ 1] + bottleneck;
+                }
+                *value = *value + bottleneck;
+                path.clear();
+                node = s;
+                continue;
+            }
+
+            let mut advanced = false;
+            while next_arc[node] < self.adjacent[node].len() {
+                let arc = self.adjacent[node][next_arc[node]];
+                let b = self.head[arc];
+                if self.residual[arc] > K::default() &&
+                    self.level[b] == self.level[node].map(|l| l + 1)
+                {
+                    path.push(arc);
+                    node = b;
+                    advanced = true;
+                    break;
+                }
+                next_arc[node] += 1;
+            }
+            if !advanced {
+                // A dead end: retreat, and don't come back here.
+                match path.pop() {
+                    Some(arc) => {
+                        node = self.head[arc ^ 1];
+                        next_arc[node] += 1;
+                    }
+                    None => return,
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,266 @@
+use std::collections::{
+    BinaryHeap,
+    HashMap,
+};
+use std::hash::Hash;
+use std::ops::{
+    Mul,
+    Sub,
+};
+
+use scored::MinScored;
+use visit::{
+    EdgeRef,
+    IntoEdgeReferences,
+    NodeIndexable,
+};
+
+use super::{
+    negative_cycle,
+    predecessor_cycle,
+    Measure,
+    NegativeCycle,
+};
+
+/// [Generic] Compute a maximum flow of minimum cost from `source` to `sink`.
+///
+/// The function `capacity` should return the capacity of a particular edge,
+/// which must be non-negative, and `cost` should return the cost per unit of
+/// flow through it. Edges are directed from their source to their target.
+///
+/// Using successive shortest paths: flow is pushed along a cheapest path in
+/// the residual network until the sink is no longer reachable. Node
+/// potentials, computed by the Bellman–Ford algorithm and then kept up to date,
+/// make the reduced costs non-negative so that Dijkstra's algorithm can be used
+/// to find each path. Negative costs are permitted, but the edges with a
+/// positive capacity must not form a cycle of negative cost (in that case
+/// it will return a `NegativeCycle` error holding such a cycle).
+///
+/// On success, return a `MinCostFlow` holding the value of the flow, its total
+/// cost and the flow on each edge.
+///
+/// **Panics** if `source` and `sink` are the same node.
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::min_cost_flow;
+///
+/// // Edge weights are (capacity, cost) pairs.
+/// let mut g = Graph::new();
+/// let s = g.add_node("s");
+/// let a = g.add_node("a");
+/// let b = g.add_node("b");
+/// let t = g.add_node("t");
+/// let sa = g.add_edge(s, a, (2, 1));
+/// let sb = g.add_edge(s, b, (1, 4));
+/// let ab = g.add_edge(a, b, (1, 1));
+/// let at = g.add_edge(a, t, (1, 5));
+/// let bt = g.add_edge(b, t, (2, 1));
+///
+/// let flow = min_cost_flow(&g, s, t, |e| e.weight().0, |e| e.weight().1).unwrap();
+/// assert_eq!(flow.value(), 3);
+/// assert_eq!(flow.cost(), 14);
+/// assert_eq!(flow.flow(sa), Some(2));
+/// assert_eq!(flow.flow(ab), Some(1));
+/// assert_eq!(flow.flow(at), Some(1));
+/// assert_eq!(flow.flow(sb), Some(1));
+/// assert_eq!(flow.flow(bt), Some(2));
+/// ```
+pub fn min_cost_flow<G, F, C, K>(g: G, source: G::NodeId, sink: G::NodeId,
+                                 mut capacity: F, mut cost: C)
+    -> Result<MinCostFlow<G::EdgeId, K>, NegativeCycle<G::NodeId, G::EdgeId>>
+    where G: IntoEdgeReferences + NodeIndexable,
+          G::EdgeId: Eq + Hash,
+          F: FnMut(G::EdgeRef) -> K,
+          C: FnMut(G::EdgeRef) -> K,
+          K: Measure + Copy + Sub<Output=K> + Mul<Output=K>,
+{
+    let (s, t) = (g.to_index(source), g.to_index(sink));
+    assert!(s != t, "min_cost_flow: source and sink must be different nodes");
+
+    let zero = K::default();
+    let n = g.node_bound();
+    let mut network = Network {
+        adjacent: vec![Vec::new(); n],
+        head: Vec::new(),
+        residual: Vec::new(),
+        cost: Vec::new(),
+    };
+    let mut edges = Vec::new();
+    for edge in g.edge_references() {
+        let (a, b) = (g.to_index(edge.source()), g.to_index(edge.target()));
+        let (cap, c) = (capacity(edge), cost(edge));
+        network.add_arc(a, b, cap, c);
+        edges.push((edge.id(), cap));
+    }
+
+    // Bellman–Ford from a virtual source with a zero-cost arc to every node.
+    let mut potential = vec![zero; n];
+    let mut predecessor = vec![None; n];
+    loop {
+        let mut did_update = false;
+        for arc in 0..network.head.len() {
+            let (a, b) = (network.head[arc ^ 1], network.head[arc]);
+            if network.residual[arc] > zero && potential[a] + network.cost[arc] < potential[b] {
+                potential[b] = potential[a] + network.cost[arc];
+                predecessor[b] = Some(arc);
+                did_update = true;
+            }
+        }
+        if !did_update {
+            break;
+        }
+        let tail = |i: usize| predecessor[i].map(|arc| network.head[arc ^ 1]);
+        if let Some(cycle) = predecessor_cycle(n, tail) {
+            return Err(negative_cycle(g, &cycle, |i| edges[predecessor[i].unwrap() / 2].0));
+        }
+    }
+
+    let mut value = zero;
+    let mut total_cost = zero;
+    loop {
+        let dist = network.shortest_paths(s, &potential, &mut predecessor);
+        if dist[t].is_none() {
+            break;
+        }
+        for (p, d) in potential.iter_mut().zip(dist) {
+            if let Some(d) = d {
+                *p = *p + d;
+            }
+        }
+
+        let mut bottleneck = None;
+        let mut path_cost = zero;
+        let mut node = t;
+        while let Some(arc) = predecessor[node] {
+            let r = network.residual[arc];
+            bottleneck = match bottleneck {
+                Some(b) if b <= r => Some(b),
+                _ => Some(r),
+            };
+            path_cost = path_cost + network.cost[arc];
+            node = network.head[arc ^ 1];
+        }
+        let bottleneck = bottleneck.unwrap();
+        let mut node = t;
+        while let Some(arc) = predecessor[node] {
+            network.residual[arc] = network.residual[arc] - bottleneck;
+            network.residual[arc ^ 1] = network.residual[arc ^ 1] + bottleneck;
+            node = network.head[arc ^ 1];
+        }
+        value = value + bottleneck;
+        total_cost = total_cost + bottleneck * path_cost;
+    }
+
+    // The arcs of each edge were added in order, forward arc first.
+    let flows = edges.into_iter().enumerate().map(|(i, (id, cap))| {
+        (id, cap - network.residual[2 * i])
+    }).collect();
+    Ok(MinCostFlow {
+        value: value,
+        cost: total_cost,
+        flows: flows,
+    })
+}
+
+/// The result of `min_cost_flow`.
+#[derive(Clone, Debug)]
+pub struct MinCostFlow<E, K>
+    where E: Eq + Hash,
+{
+    value: K,
+    cost: K,
+    flows: HashMap<E, K>,
+}
+
+impl<E, K> MinCostFlow<E, K>
+    where E: Eq + Hash,
+          K: Copy,
+{
+    /// Return the value of the flow, which is the value of a maximum flow.
+    pub fn value(&self) -> K {
+        self.value
+    }
+
+    /// Return the total cost of the flow.
+    pub fn cost(&self) -> K {
+        self.cost
+    }
+
+    /// Return the flow on `edge`, or `None` if it is not an edge of the graph.
+    pub fn flow(&self, edge: E) -> Option<K> {
+        self.flows.get(&edge).cloned()
+    }
+
+    /// Return the map of flows, which has an entry for each edge.
+    pub fn flows(&self) -> &HashMap<E, K> {
+        &self.flows
+    }
+}
+
+/// A residual network with costs, indexed by node index. Arcs are stored in
+/// pairs, so that the reverse of arc `a` is `a ^ 1`.
+struct Network<K> {
+    adjacent: Vec<Vec<usize>>,
+    head: Vec<usize>,
+    residual: Vec<K>,
+    cost: Vec<K>,
+}
+
+impl<K> Network<K>
+    where K: Measure + Copy + Sub<Output=K>,
+{
+    fn add_arc(&mut self, a: usize, b: usize, cap: K, cost: K) {
+        self.adjacent[a].push(self.head.len());
+        self.head.push(b);
+        self.residual.push(cap);
+        self.cost.push(cost);
+        self.adjacent[b].push(self.head.len());
+        self.head.push(a);
+        self.residual.push(K::default());
+        self.cost.push(K::default() - cost);
+    }
+
+    /// Dijkstra's algorithm from `s` over the arcs with residual capacity,
+    /// using the costs reduced by `potential`.
+    ///
+    /// Return the reduced distance to each node (`None` if it is unreachable),
+    /// and set `predecessor` to the arc that reaches each node.
+    fn shortest_paths(&self, s: usize, potential: &[K], predecessor: &mut [Option<usize>])
+        -> Vec<Option<K>>
+    {
+        let zero = K::default();
+        for p in predecessor.iter_mut() {
+            *p = None;
+        }
+        let mut dist = vec![None; potential.len()];
+        let mut finished = vec![false; potential.len()];
+        let mut visit_next = BinaryHeap::new();
+        dist[s] = Some(zero);
+        visit_next.push(MinScored(zero, s));
+        while let Some(MinScored(d, a)) = visit_next.pop() {
+            if finished[a] {
+                continue;
+            }
+            finished[a] = true;
+            for &arc in &self.adjacent[a] {
+                let b = self.head[arc];
+                if finished[b] || self.residual[arc] <= zero {
+                    continue;
+                }
+                let next = d + self.cost[arc] + potential[a] - potential[b];
+                let improves = match dist[b] {
+                    Some(old) => next < old,
+                    None => true,
+                };
+                if improves {
+                    dist[b] = Some(next);
+                    predecessor[b] = Some(arc);
+                    visit_next.push(MinScored(next, b));
+                }
+            }
+        }
+        dist
+    }
+}
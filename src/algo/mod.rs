@@ -4,10 +4,32 @@
 //! so that they are generally applicable. For now, some of these still require
 //! the `Graph` type.
 
+pub mod coloring;
 pub mod dominators;
-
-use std::collections::BinaryHeap;
+mod biconnected;
+mod bidirectional_dijkstra;
+mod bipartite;
+mod cycles;
+mod dag_paths;
+mod eulerian;
+mod floyd_warshall;
+mod gomory_hu;
+mod johnson;
+mod k_shortest_paths;
+mod matching;
+mod max_flow;
+mod min_cost_flow;
+mod simple_cycles;
+mod stoer_wagner;
+mod transitive;
+
+use std::collections::{
+    BinaryHeap,
+    HashMap,
+    VecDeque,
+};
 use std::cmp::min;
+use std::hash::Hash;
 
 use prelude::*;
 
@@ -44,8 +66,64 @@ pub use super::isomorphism::{
     is_isomorphic,
     is_isomorphic_matching,
 };
-pub use super::dijkstra::dijkstra;
+pub use super::dijkstra::{
+    dijkstra,
+    dijkstra_paths,
+    multi_source_dijkstra,
+    ShortestPaths,
+};
 pub use super::astar::astar;
+pub use self::biconnected::{
+    articulation_points,
+    biconnected_components,
+    bridges,
+};
+pub use self::bidirectional_dijkstra::bidirectional_dijkstra;
+pub use self::bipartite::bipartite_coloring;
+pub use self::cycles::{
+    cycle_basis,
+    girth,
+    shortest_cycle_through,
+};
+pub use self::dag_paths::{
+    dag_longest_path,
+    dag_shortest_paths,
+};
+pub use self::eulerian::{
+    eulerian_circuit,
+    eulerian_path,
+};
+pub use self::floyd_warshall::floyd_warshall;
+pub use self::gomory_hu::{
+    gomory_hu_tree,
+    GomoryHuTree,
+};
+pub use self::johnson::johnson;
+pub use self::k_shortest_paths::k_shortest_paths;
+pub use self::matching::{
+    kuhn_munkres,
+    maximum_bipartite_matching,
+    maximum_matching,
+    Matching,
+    MatchedEdges,
+};
+pub use self::max_flow::{
+    max_flow,
+    MaxFlow,
+};
+pub use self::min_cost_flow::{
+    min_cost_flow,
+    MinCostFlow,
+};
+pub use self::simple_cycles::{
+    simple_cycles,
+    SimpleCycles,
+};
+pub use self::stoer_wagner::stoer_wagner_min_cut;
+pub use self::transitive::{
+    transitive_closure,
+    transitive_reduction,
+};
 
 /// [Generic] Return the number of connected components of the graph.
 ///
@@ -137,54 +215,151 @@ pub fn toposort<G>(g: G, space: Option<&mut DfsSpace<G::NodeId, G::Map>>)
     -> Result<Vec<G::NodeId>, Cycle<G::NodeId>>
     where G: IntoNeighborsDirected + IntoNodeIdentifiers + Visitable,
 {
-    // based on kosaraju scc
+    with_dfs(g, space, |dfs| toposort_dfs(g, dfs).map_err(Cycle))
+}
+
+/// [Generic] Perform a topological sort of a directed graph, and report the
+/// full cycle if there is one.
+///
+/// This is the same as `toposort`, except that the `CyclePath` error holds a
+/// whole cycle of the graph, as its nodes in path order, together with the
+/// strongly connected component that contains it.
+///
+/// If `space` is not `None`, it is used instead of creating a new workspace for
+/// graph traversal. The implementation is iterative.
+///
+/// ```
+/// use petgraph::Graph;
+/// use petgraph::algo::toposort_with_cycle;
+///
+/// let mut g = Graph::<(), ()>::new();
+/// let a = g.add_node(());
+/// let b = g.add_node(());
+/// let c = g.add_node(());
+/// let d = g.add_node(());
+/// g.extend_with_edges(&[(d, a), (a, b), (b, c), (c, a)]);
+///
+/// let err = toposort_with_cycle(&g, None).unwrap_err();
+/// assert_eq!(err.nodes().len(), 3);
+/// assert_eq!(err.scc(), &[a, b, c]);
+/// ```
+pub fn toposort_with_cycle<G>(g: G, space: Option<&mut DfsSpace<G::NodeId, G::Map>>)
+    -> Result<Vec<G::NodeId>, CyclePath<G::NodeId>>
+    where G: IntoNeighborsDirected + IntoNodeIdentifiers + Visitable,
+{
     with_dfs(g, space, |dfs| {
-        dfs.reset(g);
-        let mut finished = g.visit_map();
+        match toposort_dfs(g, dfs) {
+            Ok(order) => Ok(order),
+            Err(node) => Err(cycle_path(g, dfs, node)),
+        }
+    })
+}
 
-        let mut finish_stack = Vec::new();
-        for i in g.node_identifiers() {
-            if dfs.discovered.is_visited(&i) {
-                continue;
-            }
-            dfs.stack.push(i);
-            while let Some(&nx) = dfs.stack.last() {
-                if dfs.discovered.visit(nx) {
-                    // First time visiting `nx`: Push neighbors, don't pop `nx`
-                    for succ in g.neighbors(nx) {
-                        if succ == nx {
-                            // self cycle
-                            return Err(Cycle(nx));
-                        }
-                        if !dfs.discovered.is_visited(&succ) {
-                            dfs.stack.push(succ);
-                        }
+/// Topological sort using `dfs` as workspace; on failure return a node that
+/// is on a cycle.
+fn toposort_dfs<G>(g: G, dfs: &mut Dfs<G::NodeId, G::Map>)
+    -> Result<Vec<G::NodeId>, G::NodeId>
+    where G: IntoNeighborsDirected + IntoNodeIdentifiers + Visitable,
+{
+    // based on kosaraju scc
+    dfs.reset(g);
+    let mut finished = g.visit_map();
+
+    let mut finish_stack = Vec::new();
+    for i in g.node_identifiers() {
+        if dfs.discovered.is_visited(&i) {
+            continue;
+        }
+        dfs.stack.push(i);
+        while let Some(&nx) = dfs.stack.last() {
+            if dfs.discovered.visit(nx) {
+                // First time visiting `nx`: Push neighbors, don't pop `nx`
+                for succ in g.neighbors(nx) {
+                    if succ == nx {
+                        // self cycle
+                        return Err(nx);
                     }
-                } else {
-                    dfs.stack.pop();
-                    if finished.visit(nx) {
-                        // Second time: All reachable nodes must have been finished
-                        finish_stack.push(nx);
+                    if !dfs.discovered.is_visited(&succ) {
+                        dfs.stack.push(succ);
                     }
                 }
+            } else {
+                dfs.stack.pop();
+                if finished.visit(nx) {
+                    // Second time: All reachable nodes must have been finished
+                    finish_stack.push(nx);
+                }
             }
         }
-        finish_stack.reverse();
+    }
+    finish_stack.reverse();
 
-        dfs.reset(g);
-        for &i in &finish_stack {
-            dfs.move_to(i);
-            let mut cycle = false;
-            while let Some(j) = dfs.next(Reversed(g)) {
-                if cycle {
-                    return Err(Cycle(j));
-                }
-                cycle = true;
+    dfs.reset(g);
+    for &i in &finish_stack {
+        dfs.move_to(i);
+        let mut cycle = false;
+        while let Some(j) = dfs.next(Reversed(g)) {
+            if cycle {
+                return Err(j);
             }
+            cycle = true;
         }
+    }
 
-        Ok(finish_stack)
-    })
+    Ok(finish_stack)
+}
+
+/// Find a cycle through `start`, which must be on a cycle, and the strongly
+/// connected component that contains it.
+fn cycle_path<G>(g: G, dfs: &mut Dfs<G::NodeId, G::Map>, start: G::NodeId)
+    -> CyclePath<G::NodeId>
+    where G: IntoNeighborsDirected + IntoNodeIdentifiers + Visitable,
+{
+    // the component is the nodes that reach `start` and are reached from it
+    let mut reaching = Vec::new();
+    dfs.reset(g);
+    dfs.move_to(start);
+    while let Some(nx) = dfs.next(Reversed(g)) {
+        reaching.push(nx);
+    }
+    dfs.reset(g);
+    dfs.move_to(start);
+    while dfs.next(g).is_some() { }
+    let mut in_scc = g.visit_map();
+    for nx in reaching {
+        if dfs.discovered.is_visited(&nx) {
+            in_scc.visit(nx);
+        }
+    }
+    let scc = g.node_identifiers().filter(|nx| in_scc.is_visited(nx)).collect();
+
+    // depth first search inside the component until an edge leads back to
+    // `start`; the path on the stack is then a cycle
+    dfs.reset(g);
+    dfs.discovered.visit(start);
+    dfs.stack.push(start);
+    let mut neighbors = vec![g.neighbors(start)];
+    while let Some(next) = neighbors.last_mut().map(|iter| iter.next()) {
+        match next {
+            Some(succ) => {
+                if succ == start {
+                    break;
+                }
+                if in_scc.is_visited(&succ) && dfs.discovered.visit(succ) {
+                    dfs.stack.push(succ);
+                    neighbors.push(g.neighbors(succ));
+                }
+            }
+            None => {
+                dfs.stack.pop();
+                neighbors.pop();
+            }
+        }
+    }
+    CyclePath {
+        nodes: dfs.stack.clone(),
+        scc: scc,
+    }
 }
 
 /// [Generic] Return `true` if the input directed graph contains a cycle.
@@ -269,6 +444,58 @@ pub fn has_path_connecting<G>(g: G, from: G::NodeId, to: G::NodeId,
     })
 }
 
+/// [Generic] Multi-source breadth-first search.
+///
+/// Compute the number of edges on a shortest path from the nearest of the
+/// `starts` nodes to every reachable node.
+///
+/// Returns a `HashMap` that maps each reachable `NodeId` to its distance and
+/// the start node that it was reached from. Where several start nodes are
+/// equally near, either of them may be reported.
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::multi_source_bfs;
+///
+/// let mut g = Graph::<(), ()>::new();
+/// let a = g.add_node(());
+/// let b = g.add_node(());
+/// let c = g.add_node(());
+/// let d = g.add_node(());
+/// g.extend_with_edges(&[(a, b), (b, c), (d, c)]);
+///
+/// let nearest = multi_source_bfs(&g, vec![a, d]);
+/// assert_eq!(nearest[&b], (1, a));
+/// assert_eq!(nearest[&c], (1, d));
+/// assert_eq!(nearest[&d], (0, d));
+/// ```
+pub fn multi_source_bfs<G, I>(g: G, starts: I) -> HashMap<G::NodeId, (usize, G::NodeId)>
+    where G: IntoNeighbors + Visitable,
+          G::NodeId: Eq + Hash,
+          I: IntoIterator<Item=G::NodeId>,
+{
+    let mut discovered = g.visit_map();
+    let mut nearest = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if discovered.visit(start) {
+            nearest.insert(start, (0, start));
+            queue.push_back(start);
+        }
+    }
+    while let Some(node) = queue.pop_front() {
+        let (dist, source) = nearest[&node];
+        for succ in g.neighbors(node) {
+            if discovered.visit(succ) {
+                nearest.insert(succ, (dist + 1, source));
+                queue.push_back(succ);
+            }
+        }
+    }
+    nearest
+}
+
 /// Renamed to `kosaraju_scc`.
 #[deprecated(note = "renamed to kosaraju_scc")]
 pub fn scc<G>(g: G) -> Vec<Vec<G::NodeId>>
@@ -643,9 +870,133 @@ impl<N> Cycle<N> {
         self.0
     }
 }
+
+/// An algorithm error: a cycle was found in the graph, returned by
+/// `toposort_with_cycle`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CyclePath<N> {
+    nodes: Vec<N>,
+    scc: Vec<N>,
+}
+
+impl<N> CyclePath<N> {
+    /// Return a node id that participates in the cycle
+    pub fn node_id(&self) -> N
+        where N: Copy
+    {
+        self.nodes[0]
+    }
+
+    /// Return the nodes of the cycle, in path order: there is an edge from
+    /// each node to the next, and from the last node to the first.
+    pub fn nodes(&self) -> &[N] {
+        &self.nodes
+    }
+
+    /// Return the strongly connected component that contains the cycle, in
+    /// the order of `node_identifiers`.
+    pub fn scc(&self) -> &[N] {
+        &self.scc
+    }
+}
+
 /// An algorithm error: a cycle of negative weights was found in the graph.
+///
+/// The error holds one such cycle, as its sequence of nodes and the edges
+/// that connect them.
+#[derive(Clone, Debug, PartialEq)]
+pub struct NegativeCycle<N, E> {
+    nodes: Vec<N>,
+    edges: Vec<E>,
+}
+
+impl<N, E> NegativeCycle<N, E> {
+    /// Return the nodes of the cycle, in path order.
+    pub fn nodes(&self) -> &[N] {
+        &self.nodes
+    }
+
+    /// Return the edges of the cycle: the edge at position `i` goes from
+    /// node `i` to node `i + 1`, and the last edge goes back to the first node.
+    pub fn edges(&self) -> &[E] {
+        &self.edges
+    }
+}
+
+/// An algorithm error: a cycle of odd length was found in the graph, so it is
+/// not bipartite.
 #[derive(Clone, Debug, PartialEq)]
-pub struct NegativeCycle(());
+pub struct OddCycle<N> {
+    nodes: Vec<N>,
+}
+
+impl<N> OddCycle<N> {
+    /// Return the nodes of the cycle, in path order: each node is joined to
+    /// the next by an edge, and the last node is joined to the first.
+    pub fn nodes(&self) -> &[N] {
+        &self.nodes
+    }
+}
+
+/// An algorithm error: there is no Eulerian path or circuit in the graph.
+#[derive(Clone, Debug, PartialEq)]
+pub enum EulerianError<N> {
+    /// The nodes with the wrong degree, or with more outgoing than incoming
+    /// edges or the other way around, in the order of `node_identifiers`.
+    Unbalanced(Vec<N>),
+    /// The degrees are right, but the edges are not all connected to each
+    /// other.
+    Disconnected,
+}
+
+/// Create a `NegativeCycle` from the node indices of a cycle of predecessors
+/// (in path order); `pred_edge(i)` is the edge from the predecessor of the
+/// node with index `i` to that node.
+fn negative_cycle<G, F>(g: G, cycle: &[usize], pred_edge: F)
+    -> NegativeCycle<G::NodeId, G::EdgeId>
+    where G: NodeIndexable,
+          F: Fn(usize) -> G::EdgeId,
+{
+    let len = cycle.len();
+    NegativeCycle {
+        nodes: cycle.iter().map(|&i| g.from_index(i)).collect(),
+        edges: (0..len).map(|k| pred_edge(cycle[(k + 1) % len])).collect(),
+    }
+}
+
+/// Find a cycle in the predecessor links of the nodes with indices
+/// `0..node_bound`, where `predecessor(i)` is the predecessor of node `i`.
+///
+/// Return the node indices of the cycle in path order (each node is
+/// the predecessor of the next one).
+fn predecessor_cycle<F>(node_bound: usize, predecessor: F) -> Option<Vec<usize>>
+    where F: Fn(usize) -> Option<usize>,
+{
+    // the walk (numbered from 1) that first reached each node
+    let mut walk = vec![0; node_bound];
+    for start in 0..node_bound {
+        let mut i = start;
+        while walk[i] == 0 {
+            walk[i] = start + 1;
+            match predecessor(i) {
+                Some(p) => i = p,
+                None => break,
+            }
+        }
+        if walk[i] == start + 1 && predecessor(i).is_some() {
+            // `i` was reached twice in this walk, so it lies on a cycle
+            let mut cycle = vec![i];
+            let mut j = predecessor(i).unwrap();
+            while j != i {
+                cycle.push(j);
+                j = predecessor(j).unwrap();
+            }
+            cycle.reverse();
+            return Some(cycle);
+        }
+    }
+    None
+}
 
 /// [Generic] Compute shortest paths from node `source` to all other.
 ///
@@ -657,6 +1008,9 @@ pub struct NegativeCycle(());
 /// out the predecessor of a node along a shortest path. The vectors
 /// are indexed by the graph's node indices.
 ///
+/// If a cycle of negative weights is reachable from `source`, return a
+/// `NegativeCycle` error holding the nodes and edges of such a cycle.
+///
 /// [bf]: https://en.wikipedia.org/wiki/Bellman%E2%80%93Ford_algorithm
 ///
 /// # Example
@@ -711,20 +1065,55 @@ pub struct NegativeCycle(());
 /// ]);
 ///
 /// assert!(bellman_ford(&graph_with_neg_cycle, NodeIndex::new(0)).is_err());
+///
+/// // The error holds the cycle: here, a -> b -> c -> a costs -1.
+/// let mut g = Graph::new();
+/// let a = g.add_node(());
+/// let b = g.add_node(());
+/// let c = g.add_node(());
+/// let ab = g.add_edge(a, b, 1.0);
+/// let bc = g.add_edge(b, c, 1.0);
+/// let ca = g.add_edge(c, a, -3.0);
+/// let cycle = bellman_ford(&g, a).unwrap_err();
+/// // the cycle may start at any of its nodes
+/// let start = cycle.nodes().iter().position(|&n| n == a).unwrap();
+/// let nodes: Vec<_> = (0..3).map(|i| cycle.nodes()[(start + i) % 3]).collect();
+/// let edges: Vec<_> = (0..3).map(|i| cycle.edges()[(start + i) % 3]).collect();
+/// assert_eq!(nodes, vec![a, b, c]);
+/// assert_eq!(edges, vec![ab, bc, ca]);
 /// ```
 pub fn bellman_ford<G>(g: G, source: G::NodeId)
-    -> Result<(Vec<G::EdgeWeight>, Vec<Option<G::NodeId>>), NegativeCycle>
+    -> Result<(Vec<G::EdgeWeight>, Vec<Option<G::NodeId>>), NegativeCycle<G::NodeId, G::EdgeId>>
     where G: NodeCount + IntoNodeIdentifiers + IntoEdges + NodeIndexable,
           G::EdgeWeight: FloatMeasure,
 {
     let mut predecessor = vec![None; g.node_bound()];
     let mut distance = vec![<_>::infinite(); g.node_bound()];
 
+    distance[g.to_index(source)] = <_>::zero();
+    bellman_ford_relax(g, &mut distance, &mut predecessor)?;
+
+    Ok((distance, predecessor))
+}
+
+/// Run the Bellman–Ford relaxation passes, starting from the given initial
+/// `distance` and `predecessor` vectors (indexed by the graph's node indices).
+///
+/// Setting every initial distance to zero is equivalent to starting from a
+/// virtual source with a zero-cost edge to every node.
+fn bellman_ford_relax<G>(g: G, distance: &mut [G::EdgeWeight],
+                         predecessor: &mut [Option<G::NodeId>])
+    -> Result<(), NegativeCycle<G::NodeId, G::EdgeId>>
+    where G: NodeCount + IntoNodeIdentifiers + IntoEdges + NodeIndexable,
+          G::EdgeWeight: FloatMeasure,
+{
     let ix = |i| g.to_index(i);
+    let mut pred_edge = vec![None; g.node_bound()];
 
-    distance[ix(source)] = <_>::zero();
-    // scan up to |V| - 1 times.
-    for _ in 1..g.node_count() {
+    // relax all edges once, return true if any distance was updated
+    let relax = |distance: &mut [G::EdgeWeight],
+                 predecessor: &mut [Option<G::NodeId>],
+                 pred_edge: &mut [Option<G::EdgeId>]| {
         let mut did_update = false;
         for i in g.node_identifiers() {
             for edge in g.edges(i) {
@@ -734,28 +1123,31 @@ pub fn bellman_ford<G>(g: G, source: G::NodeId)
                 if distance[ix(i)] + w < distance[ix(j)] {
                     distance[ix(j)] = distance[ix(i)] + w;
                     predecessor[ix(j)] = Some(i);
+                    pred_edge[ix(j)] = Some(edge.id());
                     did_update = true;
                 }
             }
         }
-        if !did_update {
-            break;
+        did_update
+    };
+
+    // scan up to |V| - 1 times.
+    for _ in 1..g.node_count() {
+        if !relax(distance, predecessor, &mut pred_edge) {
+            return Ok(());
         }
     }
 
-    // check for negative weight cycle
-    for i in g.node_identifiers() {
-        for edge in g.edges(i) {
-            let j = edge.target();
-            let w = *edge.weight();
-            if distance[ix(i)] + w < distance[ix(j)] {
-                //println!("neg cycle, detected from {} to {}, weight={}", i, j, w);
-                return Err(NegativeCycle(()));
-            }
+    // check for negative weight cycle: if distances still improve, the cycle
+    // shows up in the predecessor links after enough further scans.
+    while relax(distance, predecessor, &mut pred_edge) {
+        let cycle = predecessor_cycle(g.node_bound(), |i| predecessor[i].map(|n| g.to_index(n)));
+        if let Some(cycle) = cycle {
+            return Err(negative_cycle(g, &cycle, |i| pred_edge[i].unwrap()));
         }
     }
 
-    Ok((distance, predecessor))
+    Ok(())
 }
 
 use std::ops::Add;
@@ -0,0 +1,208 @@
+use visit::{
+    IntoNeighbors,
+    IntoNodeIdentifiers,
+    NodeFiltered,
+    NodeIndexable,
+};
+
+use super::tarjan_scc;
+
+/// [Generic] Enumerate the simple cycles of a directed graph.
+///
+/// A simple (or elementary) cycle is a closed path that visits no node more
+/// than once. Each cycle is reported once, as the nodes along it starting
+/// from the one with the smallest node index; a self loop is a cycle of one
+/// node. Parallel edges do not make distinct cycles.
+///
+/// Using [Johnson's algorithm][johnson], which searches each strongly
+/// connected component (as found by `tarjan_scc`) for the cycles through its
+/// node with the smallest node index, then removes that node and carries on
+/// with what is left. The cycles are found lazily, so the number of cycles
+/// to look for can be limited with `take`; finding `c` cycles takes
+/// **O((|V| + |E|)·(|V| + c))** time.
+///
+/// [johnson]: https://www.cs.tufts.edu/comp/150GA/homeworks/hw1/Johnson%2075.PDF
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::simple_cycles;
+///
+/// let mut g = Graph::<&str, ()>::new();
+/// let a = g.add_node("a");
+/// let b = g.add_node("b");
+/// let c = g.add_node("c");
+/// let d = g.add_node("d");
+/// g.extend_with_edges(&[(a, b), (b, c), (c, a), (b, a), (c, d), (d, d)]);
+///
+/// let mut cycles: Vec<_> = simple_cycles(&g).collect();
+/// cycles.sort();
+/// assert_eq!(cycles, vec![vec![a, b], vec![a, b, c], vec![d]]);
+/// assert_eq!(simple_cycles(&g).take(2).count(), 2);
+/// ```
+pub fn simple_cycles<G>(g: G) -> SimpleCycles<G>
+    where G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    let n = g.node_bound();
+    let mut cycles = SimpleCycles {
+        graph: g,
+        work: Vec::new(),
+        in_component: vec![false; n],
+        component: Vec::new(),
+        start: 0,
+        blocked: vec![false; n],
+        block_map: vec![Vec::new(); n],
+        path: Vec::new(),
+        frames: Vec::new(),
+    };
+    cycles.push_components(tarjan_scc(g));
+    cycles
+}
+
+/// An iterator over the simple cycles of a graph, created by
+/// `simple_cycles`.
+pub struct SimpleCycles<G> {
+    graph: G,
+    /// The strongly connected components that are left to search, as node
+    /// indices.
+    work: Vec<Vec<usize>>,
+    /// The component that is being searched.
+    in_component: Vec<bool>,
+    component: Vec<usize>,
+    /// The node that all cycles found in the component go through.
+    start: usize,
+    /// The nodes that can't lead back to `start` without passing through
+    /// the path, and for each node, the blocked nodes that are unblocked
+    /// along with it.
+    blocked: Vec<bool>,
+    block_map: Vec<Vec<usize>>,
+    /// The path from `start`, and for each node on it, its neighbors in the
+    /// component, how many of them have been tried, and whether a cycle was
+    /// found through it.
+    path: Vec<usize>,
+    frames: Vec<(Vec<usize>, usize, bool)>,
+}
+
+impl<G> SimpleCycles<G>
+    where G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    /// Add the components that can have cycles to the work list.
+    fn push_components(&mut self, components: Vec<Vec<G::NodeId>>) {
+        let g = self.graph;
+        for component in components {
+            let has_cycle = component.len() > 1 ||
+                g.neighbors(component[0]).any(|n| n == component[0]);
+            if has_cycle {
+                self.work.push(component.into_iter().map(|n| g.to_index(n)).collect());
+            }
+        }
+    }
+
+    /// Return the distinct neighbors of `a` in the component.
+    fn neighbors(&self, a: usize) -> Vec<usize> {
+        let g = self.graph;
+        let mut neighbors: Vec<usize> = g.neighbors(g.from_index(a))
+                                         .map(|n| g.to_index(n))
+                                         .filter(|&b| self.in_component[b])
+                                         .collect();
+        neighbors.sort();
+        neighbors.dedup();
+        neighbors
+    }
+
+    /// Start searching `component` for cycles, and put what is left of it
+    /// once the search is done on the work list.
+    fn begin(&mut self, component: Vec<usize>) {
+        for &a in &self.component {
+            self.in_component[a] = false;
+        }
+        for &a in &component {
+            self.in_component[a] = true;
+            self.blocked[a] = false;
+            self.block_map[a].clear();
+        }
+        self.start = *component.iter().min().unwrap();
+        self.component = component;
+
+        let g = self.graph;
+        self.in_component[self.start] = false;
+        let rest = {
+            let in_rest = &self.in_component;
+            tarjan_scc(&NodeFiltered::from_fn(g, |n| in_rest[g.to_index(n)]))
+        };
+        self.push_components(rest);
+        self.in_component[self.start] = true;
+
+        let start = self.start;
+        self.blocked[start] = true;
+        self.path.push(start);
+        let neighbors = self.neighbors(start);
+        self.frames.push((neighbors, 0, false));
+    }
+
+    /// Unblock `a`, and the nodes that were waiting on it.
+    fn unblock(&mut self, a: usize) {
+        let mut stack = vec![a];
+        while let Some(x) = stack.pop() {
+            if self.blocked[x] {
+                self.blocked[x] = false;
+                stack.append(&mut self.block_map[x]);
+            }
+        }
+    }
+}
+
+impl<G> Iterator for SimpleCycles<G>
+    where G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable,
+{
+    type Item = Vec<G::NodeId>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let top = match self.frames.len() {
+                0 => {
+                    match self.work.pop() {
+                        Some(component) => self.begin(component),
+                        None => return None,
+                    }
+                    continue;
+                }
+                len => len - 1,
+            };
+            let v = self.path[top];
+            let next = self.frames[top].1;
+            if next < self.frames[top].0.len() {
+                let w = self.frames[top].0[next];
+                self.frames[top].1 += 1;
+                if w == self.start {
+                    self.frames[top].2 = true;
+                    let g = self.graph;
+                    return Some(self.path.iter().map(|&a| g.from_index(a)).collect());
+                } else if !self.blocked[w] {
+                    self.blocked[w] = true;
+                    self.path.push(w);
+                    let neighbors = self.neighbors(w);
+                    self.frames.push((neighbors, 0, false));
+                }
+                continue;
+            }
+
+            // All neighbors of `v` are done.
+            let (neighbors, _, found_cycle) = self.frames.pop().unwrap();
+            self.path.pop();
+            if found_cycle {
+                self.unblock(v);
+                if let Some(parent) = self.frames.last_mut() {
+                    parent.2 = true;
+                }
+            } else {
+                // `v` stays blocked until one of its neighbors is unblocked.
+                for w in neighbors {
+                    if !self.block_map[w].contains(&v) {
+                        self.block_map[w].push(v);
+                    }
+                }
+            }
+        }
+    }
+}
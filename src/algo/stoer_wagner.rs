@@ -0,0 +1,141 @@
+use visit::{
+    EdgeRef,
+    IntoEdgeReferences,
+    IntoNodeIdentifiers,
+    NodeIndexable,
+};
+
+use super::Measure;
+
+/// [Generic] Compute a global minimum cut of an undirected graph.
+///
+/// Using the [Stoer–Wagner algorithm][sw] with runtime **O(|V|³)**. A cut
+/// splits the nodes into two non-empty sets; its weight is the total weight
+/// of the edges between them. No source or sink is needed: the cut of least
+/// weight over all ways to split the graph is found.
+///
+/// The function `edge_weight` should return the weight of a particular edge,
+/// which must be non-negative. Edge directions are ignored, and self loops
+/// never cross a cut.
+///
+/// Return the weight of a minimum cut and the nodes on one side of it, in
+/// order of their node indices, or `None` if the graph has fewer than two
+/// nodes.
+///
+/// [sw]: https://en.wikipedia.org/wiki/Stoer%E2%80%93Wagner_algorithm
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::stoer_wagner_min_cut;
+///
+/// // Two triangles joined by a single light edge.
+/// let mut g = Graph::new_undirected();
+/// let a = g.add_node(());
+/// let b = g.add_node(());
+/// let c = g.add_node(());
+/// let d = g.add_node(());
+/// let e = g.add_node(());
+/// let f = g.add_node(());
+/// g.extend_with_edges(&[
+///     (a, b, 3), (b, c, 3), (c, a, 3),
+///     (d, e, 3), (e, f, 3), (f, d, 3),
+///     (c, d, 2),
+/// ]);
+///
+/// let (weight, side) = stoer_wagner_min_cut(&g, |e| *e.weight()).unwrap();
+/// assert_eq!(weight, 2);
+/// assert!(side == vec![a, b, c] || side == vec![d, e, f]);
+/// ```
+pub fn stoer_wagner_min_cut<G, F, K>(g: G, mut edge_weight: F)
+    -> Option<(K, Vec<G::NodeId>)>
+    where G: IntoEdgeReferences + IntoNodeIdentifiers + NodeIndexable,
+          F: FnMut(G::EdgeRef) -> K,
+          K: Measure + Copy,
+{
+    let nodes: Vec<_> = g.node_identifiers().collect();
+    let n = nodes.len();
+    if n < 2 {
+        return None;
+    }
+    let mut compact = vec![0; g.node_bound()];
+    for (i, &node) in nodes.iter().enumerate() {
+        compact[g.to_index(node)] = i;
+    }
+
+    let zero = K::default();
+    let mut weight = vec![vec![zero; n]; n];
+    for edge in g.edge_references() {
+        let a = compact[g.to_index(edge.source())];
+        let b = compact[g.to_index(edge.target())];
+        if a != b {
+            let w = edge_weight(edge);
+            weight[a][b] = weight[a][b] + w;
+            weight[b][a] = weight[b][a] + w;
+        }
+    }
+
+    // The original nodes that have been merged into each node.
+    let mut members: Vec<Vec<usize>> = (0..n).map(|i| vec![i]).collect();
+    let mut active: Vec<usize> = (0..n).collect();
+    let mut best: Option<(K, Vec<usize>)> = None;
+    let mut connectivity = vec![zero; n];
+    let mut added = vec![false; n];
+
+    while active.len() > 1 {
+        // Add nodes one at a time, always the one most tightly connected to
+        // those already added; the last two are `s` and `t`.
+        for &v in &active {
+            connectivity[v] = zero;
+            added[v] = false;
+        }
+        let mut s = active[0];
+        let mut t = active[0];
+        for _ in 0..active.len() {
+            let mut next = None;
+            for &v in &active {
+                if added[v] {
+                    continue;
+                }
+                next = match next {
+                    Some(u) if connectivity[u] >= connectivity[v] => Some(u),
+                    _ => Some(v),
+                };
+            }
+            let next = next.unwrap();
+            added[next] = true;
+            s = t;
+            t = next;
+            for &v in &active {
+                if !added[v] {
+                    connectivity[v] = connectivity[v] + weight[next][v];
+                }
+            }
+        }
+
+        // The cut of the phase separates `t` from everything else.
+        let improves = match best {
+            Some((w, _)) => connectivity[t] < w,
+            None => true,
+        };
+        if improves {
+            best = Some((connectivity[t], members[t].clone()));
+        }
+
+        // Merge `t` into `s`.
+        for &v in &active {
+            weight[s][v] = weight[s][v] + weight[t][v];
+            weight[v][s] = weight[s][v];
+        }
+        weight[s][s] = zero;
+        let merged = ::std::mem::replace(&mut members[t], Vec::new());
+        members[s].extend(merged);
+        active.retain(|&v| v != t);
+    }
+
+    best.map(|(w, side)| {
+        let mut side: Vec<_> = side.into_iter().map(|i| nodes[i]).collect();
+        side.sort_by_key(|&n| g.to_index(n));
+        (w, side)
+    })
+}
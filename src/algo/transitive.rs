@@ -0,0 +1,171 @@
+use fixedbitset::FixedBitSet;
+
+use visit::{
+    IntoNeighbors,
+    IntoNodeIdentifiers,
+    NodeIndexable,
+    Visitable,
+    VisitMap,
+};
+
+use super::Cycle;
+
+/// [Generic] Compute the transitive closure of a directed acyclic graph.
+///
+/// Return every pair `(a, b)` of distinct nodes such that there is a path from
+/// `a` to `b`, grouped by `a` in the order of `node_identifiers` and then
+/// sorted by the index of `b`. If the graph has a cycle, return a `Cycle`
+/// error.
+///
+/// The graph should be `Visitable` and implement `IntoNeighbors`,
+/// `IntoNodeIdentifiers` and `NodeIndexable`, so it can be a `Graph`,
+/// `StableGraph` or `Csr`. Reachability is stored as a bitmap with one row per
+/// node, like the adjacency matrix of `GetAdjacencyMatrix`.
+///
+/// The time complexity is **O(|V| (|V| + |E|))** and the bitmap uses
+/// **O(|V|²)** bits.
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::transitive_closure;
+///
+/// let mut g = Graph::<(), ()>::new();
+/// let a = g.add_node(());
+/// let b = g.add_node(());
+/// let c = g.add_node(());
+/// g.extend_with_edges(&[(a, b), (b, c)]);
+///
+/// assert_eq!(transitive_closure(&g), Ok(vec![(a, b), (a, c), (b, c)]));
+/// ```
+pub fn transitive_closure<G>(g: G) -> Result<Vec<(G::NodeId, G::NodeId)>, Cycle<G::NodeId>>
+    where G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable + Visitable,
+{
+    let reach = reachability(g)?;
+    let n = g.node_bound();
+    let mut closure = Vec::new();
+    for a in g.node_identifiers() {
+        let row = g.to_index(a) * n;
+        for j in 0..n {
+            if reach.contains(row + j) {
+                closure.push((a, g.from_index(j)));
+            }
+        }
+    }
+    Ok(closure)
+}
+
+/// [Generic] Compute the transitive reduction of a directed acyclic graph.
+///
+/// Return the pairs `(a, b)` such that there is an edge from `a` to `b`, and
+/// no other path from `a` to `b`. These are the fewest edges that give the
+/// same reachability as the graph; parallel edges are reported once. The pairs
+/// are grouped by `a` in the order of `node_identifiers` and then sorted by the
+/// index of `b`. If the graph has a cycle, return a `Cycle` error.
+///
+/// The graph should be `Visitable` and implement `IntoNeighbors`,
+/// `IntoNodeIdentifiers` and `NodeIndexable`, so it can be a `Graph`,
+/// `StableGraph` or `Csr`. Reachability is stored as a bitmap with one row per
+/// node, like the adjacency matrix of `GetAdjacencyMatrix`.
+///
+/// The time complexity is **O(|V| (|V| + |E|))** and the bitmap uses
+/// **O(|V|²)** bits.
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::transitive_reduction;
+///
+/// let mut g = Graph::<(), ()>::new();
+/// let a = g.add_node(());
+/// let b = g.add_node(());
+/// let c = g.add_node(());
+/// g.extend_with_edges(&[(a, b), (b, c), (a, c)]);
+///
+/// // a → c is implied by a → b → c
+/// let reduced = transitive_reduction(&g).unwrap();
+/// assert_eq!(reduced, vec![(a, b), (b, c)]);
+///
+/// let g = Graph::<(), ()>::from_edges(&reduced);
+/// assert_eq!(g.edge_count(), 2);
+/// ```
+pub fn transitive_reduction<G>(g: G) -> Result<Vec<(G::NodeId, G::NodeId)>, Cycle<G::NodeId>>
+    where G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable + Visitable,
+{
+    let reach = reachability(g)?;
+    let n = g.node_bound();
+    let mut direct = FixedBitSet::with_capacity(n);
+    let mut indirect = FixedBitSet::with_capacity(n);
+    let mut reduction = Vec::new();
+    for a in g.node_identifiers() {
+        direct.clear();
+        indirect.clear();
+        for succ in g.neighbors(a) {
+            direct.put(g.to_index(succ));
+            // anything reachable from a successor has a path of length two
+            let row = g.to_index(succ) * n;
+            for j in 0..n {
+                if reach.contains(row + j) {
+                    indirect.put(j);
+                }
+            }
+        }
+        for j in 0..n {
+            if direct.contains(j) && !indirect.contains(j) {
+                reduction.push((a, g.from_index(j)));
+            }
+        }
+    }
+    Ok(reduction)
+}
+
+/// Return the bitmap with a bit for each `(a, b)` at `a * node_bound + b`,
+/// set if there is a path of at least one edge from `a` to `b`.
+fn reachability<G>(g: G) -> Result<FixedBitSet, Cycle<G::NodeId>>
+    where G: IntoNeighbors + IntoNodeIdentifiers + NodeIndexable + Visitable,
+{
+    let n = g.node_bound();
+    let mut reach = FixedBitSet::with_capacity(n * n);
+    let mut discovered = g.visit_map();
+    let mut finished = g.visit_map();
+    let mut stack = Vec::new();
+    for start in g.node_identifiers() {
+        if discovered.is_visited(&start) {
+            continue;
+        }
+        stack.push(start);
+        while let Some(&nx) = stack.last() {
+            if discovered.visit(nx) {
+                // The discovered but unfinished nodes are the current path
+                // (including `nx`), so an edge to one of them closes a cycle
+                for succ in g.neighbors(nx) {
+                    if discovered.is_visited(&succ) && !finished.is_visited(&succ) {
+                        return Err(Cycle(succ));
+                    }
+                    if !discovered.is_visited(&succ) {
+                        stack.push(succ);
+                    }
+                }
+            } else {
+                stack.pop();
+                if finished.visit(nx) {
+                    // All successors are finished: reach[nx] is their union
+                    let row = g.to_index(nx) * n;
+                    for succ in g.neighbors(nx) {
+                        if reach.put(row + g.to_index(succ)) {
+                            // already reached through an earlier successor
+                            continue;
+                        }
+                        let succ_row = g.to_index(succ) * n;
+                        for j in 0..n {
+                            if reach.contains(succ_row + j) {
+                                reach.put(row + j);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+    Ok(reach)
+}
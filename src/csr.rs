@@ -706,6 +706,7 @@ mod tests {
     use visit::VisitMap;
     use algo::tarjan_scc;
     use algo::bellman_ford;
+    use algo::maximum_bipartite_matching;
 
     #[test]
     fn csr1() {
@@ -887,6 +888,24 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_bipartite_matching() {
+        // nodes 0..3 on the left, 3..6 on the right
+        let m: Csr<(), ()> = Csr::from_sorted_edges(&[
+            (0, 3),
+            (0, 4),
+            (1, 3),
+            (2, 4),
+            (2, 5),
+        ]).unwrap();
+        let matching = maximum_bipartite_matching(&m, vec![0, 1, 2]);
+        // the edges are numbered in order
+        assert_eq!(matching, vec![1, 2, 4]);
+        let matching = maximum_bipartite_matching(&m, vec![1]);
+        assert_eq!(matching, vec![2]);
+        assert_eq!(maximum_bipartite_matching(&m, vec![3, 4, 5]), vec![]);
+    }
+
     #[test]
     fn test_edge_references() {
         use visit::EdgeRef;
@@ -81,20 +81,156 @@ use algo::Measure;
 /// // z is not inside res because there is not path from b to z.
 /// ```
 pub fn dijkstra<G, F, K>(graph: G, start: G::NodeId, goal: Option<G::NodeId>,
-                         mut edge_cost: F)
+                         edge_cost: F)
     -> HashMap<G::NodeId, K>
     where G: IntoEdges + Visitable,
           G::NodeId: Eq + Hash,
           F: FnMut(G::EdgeRef) -> K,
           K: Measure + Copy,
+{
+    dijkstra_impl(graph, Some((start, K::default())), goal, edge_cost, |_, _, _| ())
+}
+
+/// [Generic] Dijkstra's shortest path algorithm, recording the shortest paths.
+///
+/// This is the same as `dijkstra`, but the result also records the
+/// predecessor node and edge of each reached node, so that the path to any
+/// of them can be reconstructed with `ShortestPaths::path_to` and
+/// `ShortestPaths::edge_path_to`.
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::dijkstra_paths;
+///
+/// let mut g = Graph::new();
+/// let a = g.add_node(());
+/// let b = g.add_node(());
+/// let c = g.add_node(());
+/// let d = g.add_node(());
+/// let ab = g.add_edge(a, b, 1);
+/// let bc = g.add_edge(b, c, 2);
+/// g.add_edge(a, c, 4);
+/// let cd = g.add_edge(c, d, 1);
+///
+/// let paths = dijkstra_paths(&g, a, None, |e| *e.weight());
+/// assert_eq!(paths.score(d), Some(&4));
+/// assert_eq!(paths.predecessor(d), Some((c, cd)));
+/// assert_eq!(paths.path_to(d), Some(vec![a, b, c, d]));
+/// assert_eq!(paths.edge_path_to(d), Some(vec![ab, bc, cd]));
+/// assert_eq!(paths.path_to(a), Some(vec![a]));
+/// ```
+pub fn dijkstra_paths<G, F, K>(graph: G, start: G::NodeId, goal: Option<G::NodeId>,
+                               edge_cost: F)
+    -> ShortestPaths<G::NodeId, G::EdgeId, K>
+    where G: IntoEdges + Visitable,
+          G::NodeId: Eq + Hash,
+          F: FnMut(G::EdgeRef) -> K,
+          K: Measure + Copy,
+{
+    let mut predecessors = HashMap::new();
+    let scores = dijkstra_impl(graph, Some((start, K::default())), goal, edge_cost,
+                               |next, node, edge| {
+        predecessors.insert(next, (node, edge));
+    });
+    ShortestPaths {
+        scores: scores,
+        predecessors: predecessors,
+    }
+}
+
+/// [Generic] Multi-source Dijkstra's shortest path algorithm.
+///
+/// Compute the length of the shortest path from the nearest of several start
+/// nodes to every reachable node, as if a virtual source had an edge to each
+/// start node with that start's initial cost. `starts` yields each start node
+/// together with its initial cost (usually zero).
+///
+/// The graph should be `Visitable` and implement `IntoEdges`. The function
+/// `edge_cost` should return the cost for a particular edge. Edge costs and
+/// initial costs must be non-negative.
+///
+/// Returns a `HashMap` that maps each reachable `NodeId` to its path cost and
+/// the start node that it was reached from. Where several start nodes are
+/// equally near, either of them may be reported.
+///
+/// # Example
+/// ```rust
+/// use petgraph::Graph;
+/// use petgraph::algo::multi_source_dijkstra;
+///
+/// let mut g = Graph::new_undirected();
+/// let a = g.add_node(());
+/// let b = g.add_node(());
+/// let c = g.add_node(());
+/// let d = g.add_node(());
+/// let e = g.add_node(());
+/// g.extend_with_edges(&[
+///     (a, b, 1),
+///     (b, c, 3),
+///     (c, d, 1),
+///     (d, e, 2),
+/// ]);
+///
+/// // The nearest of `a` and `e` to every node.
+/// let nearest = multi_source_dijkstra(&g, vec![(a, 0), (e, 0)], |e| *e.weight());
+/// assert_eq!(nearest[&b], (1, a));
+/// assert_eq!(nearest[&c], (3, e));
+/// assert_eq!(nearest[&e], (0, e));
+///
+/// // Starting from `e` costs 2 more, so `c` is now nearer to `a`.
+/// let nearest = multi_source_dijkstra(&g, vec![(a, 0), (e, 2)], |e| *e.weight());
+/// assert_eq!(nearest[&c], (4, a));
+/// assert_eq!(nearest[&d], (4, e));
+/// ```
+pub fn multi_source_dijkstra<G, I, F, K>(graph: G, starts: I, edge_cost: F)
+    -> HashMap<G::NodeId, (K, G::NodeId)>
+    where G: IntoEdges + Visitable,
+          G::NodeId: Eq + Hash,
+          I: IntoIterator<Item=(G::NodeId, K)>,
+          F: FnMut(G::EdgeRef) -> K,
+          K: Measure + Copy,
+{
+    let starts: Vec<_> = starts.into_iter().collect();
+    let mut sources: HashMap<_, _> = starts.iter().map(|&(n, _)| (n, n)).collect();
+    let scores = dijkstra_impl(graph, starts, None, edge_cost, |next, node, _| {
+        let source = sources[&node];
+        sources.insert(next, source);
+    });
+    scores.into_iter()
+          .map(|(n, score)| (n, (score, sources[&n])))
+          .collect()
+}
+
+/// Dijkstra's algorithm from each of `starts` with its initial score;
+/// `set_predecessor` is called with the node, its new predecessor and the edge
+/// between them each time a node's score improves.
+fn dijkstra_impl<G, I, F, K, P>(graph: G, starts: I, goal: Option<G::NodeId>,
+                                mut edge_cost: F, mut set_predecessor: P)
+    -> HashMap<G::NodeId, K>
+    where G: IntoEdges + Visitable,
+          G::NodeId: Eq + Hash,
+          I: IntoIterator<Item=(G::NodeId, K)>,
+          F: FnMut(G::EdgeRef) -> K,
+          P: FnMut(G::NodeId, G::NodeId, G::EdgeId),
+          K: Measure + Copy,
 {
     let mut visited = graph.visit_map();
     let mut scores = HashMap::new();
-    //let mut predecessor = HashMap::new();
     let mut visit_next = BinaryHeap::new();
-    let zero_score = K::default();
-    scores.insert(start, zero_score);
-    visit_next.push(MinScored(zero_score, start));
+    for (start, start_score) in starts {
+        match scores.entry(start) {
+            Occupied(ent) => if start_score < *ent.get() {
+                *ent.into_mut() = start_score;
+            } else {
+                continue
+            },
+            Vacant(ent) => {
+                ent.insert(start_score);
+            }
+        }
+        visit_next.push(MinScored(start_score, start));
+    }
     while let Some(MinScored(node_score, node)) = visit_next.pop() {
         if visited.is_visited(&node) {
             continue
@@ -111,13 +247,13 @@ pub fn dijkstra<G, F, K>(graph: G, start: G::NodeId, goal: Option<G::NodeId>,
             match scores.entry(next) {
                 Occupied(ent) => if next_score < *ent.get() {
                     *ent.into_mut() = next_score;
-                    //predecessor.insert(next.clone(), node.clone());
+                    set_predecessor(next, node, edge.id());
                 } else {
                     next_score = *ent.get();
                 },
                 Vacant(ent) => {
                     ent.insert(next_score);
-                    //predecessor.insert(next.clone(), node.clone());
+                    set_predecessor(next, node, edge.id());
                 }
             }
             visit_next.push(MinScored(next_score, next));
@@ -126,3 +262,74 @@ pub fn dijkstra<G, F, K>(graph: G, start: G::NodeId, goal: Option<G::NodeId>,
     }
     scores
 }
+
+/// The result of `dijkstra_paths`: path costs and the predecessor of each
+/// reached node along a shortest path.
+#[derive(Clone, Debug)]
+pub struct ShortestPaths<N, E, K>
+    where N: Eq + Hash,
+{
+    scores: HashMap<N, K>,
+    predecessors: HashMap<N, (N, E)>,
+}
+
+impl<N, E, K> ShortestPaths<N, E, K>
+    where N: Copy + Eq + Hash,
+          E: Copy,
+{
+    /// Return the map of path costs, which has an entry for each reached node.
+    pub fn scores(&self) -> &HashMap<N, K> {
+        &self.scores
+    }
+
+    /// Return the map of path costs, consuming `self`.
+    pub fn into_scores(self) -> HashMap<N, K> {
+        self.scores
+    }
+
+    /// Return the cost of the shortest path to `node`, or `None` if it was
+    /// not reached.
+    pub fn score(&self, node: N) -> Option<&K> {
+        self.scores.get(&node)
+    }
+
+    /// Return the node before `node` on its shortest path, together with the
+    /// edge connecting them.
+    ///
+    /// Return `None` for the start node and for nodes that were not reached.
+    pub fn predecessor(&self, node: N) -> Option<(N, E)> {
+        self.predecessors.get(&node).cloned()
+    }
+
+    /// Return the nodes of the shortest path to `target`, starting with the
+    /// start node and ending with `target`, or `None` if it was not reached.
+    pub fn path_to(&self, target: N) -> Option<Vec<N>> {
+        if !self.scores.contains_key(&target) {
+            return None;
+        }
+        let mut path = vec![target];
+        let mut current = target;
+        while let Some(&(previous, _)) = self.predecessors.get(&current) {
+            path.push(previous);
+            current = previous;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Return the edges of the shortest path to `target`, in order from the
+    /// start node, or `None` if it was not reached.
+    pub fn edge_path_to(&self, target: N) -> Option<Vec<E>> {
+        if !self.scores.contains_key(&target) {
+            return None;
+        }
+        let mut path = Vec::new();
+        let mut current = target;
+        while let Some(&(previous, edge)) = self.predecessors.get(&current) {
+            path.push(edge);
+            current = previous;
+        }
+        path.reverse();
+        Some(path)
+    }
+}
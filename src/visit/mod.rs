@@ -15,11 +15,15 @@
 //! `.next()` call on the walker. They can be converted to iterators
 //! through the [`Walker`][w] trait.
 //!
+//! [`Dijkstra`][dijkstra] is a walker too; it emits nodes in order of their
+//! distance from the start.
+//!
 //! There is also the callback based traversal [`depth_first_search`][dfs].
 //!
 //! [bfs]: struct.Bfs.html
 //! [dfspo]: struct.DfsPostOrder.html
 //! [topo]: struct.Topo.html
+//! [dijkstra]: struct.Dijkstra.html
 //! [dfs]: fn.depth_first_search.html
 //! [w]: trait.Walker.html
 //!
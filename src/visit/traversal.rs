@@ -2,7 +2,13 @@
 use {Incoming};
 use super::{IntoNeighbors, IntoNeighborsDirected, Visitable, VisitMap};
 use super::{GraphRef, Reversed, IntoNodeIdentifiers};
-use std::collections::VecDeque;
+use super::{EdgeRef, IntoEdges};
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::collections::hash_map::Entry::{Occupied, Vacant};
+use std::hash::Hash;
+
+use algo::Measure;
+use scored::MinScored;
 
 /// Visit nodes of a graph in a depth-first-search (DFS) emitting nodes in
 /// preorder (when they are first discovered).
@@ -247,6 +253,28 @@ impl<N, VM> Bfs<N, VM>
         }
     }
 
+    /// Create a new **Bfs**, using the graph's visitor map, and put each of
+    /// **starts** in the stack of nodes to visit.
+    ///
+    /// Nodes are then visited in order of their distance (in edges) from the
+    /// nearest start node.
+    pub fn with_starts<G, I>(graph: G, starts: I) -> Self
+        where G: GraphRef + Visitable<NodeId=N, Map=VM>,
+              I: IntoIterator<Item=N>,
+    {
+        let mut discovered = graph.visit_map();
+        let mut stack = VecDeque::new();
+        for start in starts {
+            if discovered.visit(start) {
+                stack.push_back(start);
+            }
+        }
+        Bfs {
+            stack: stack,
+            discovered: discovered,
+        }
+    }
+
     /// Return the next node in the bfs, or **None** if the traversal is done.
     pub fn next<G>(&mut self, graph: G) -> Option<N>
         where G: IntoNeighbors<NodeId=N>
@@ -265,6 +293,157 @@ impl<N, VM> Bfs<N, VM>
 
 }
 
+/// A Dijkstra shortest path traversal of a graph, emitting nodes together
+/// with their distance from the start, in the order they are settled.
+///
+/// The traversal starts at a given node and only traverses nodes reachable
+/// from it. The function `edge_cost` should return the cost for a particular
+/// edge; edge costs must be non-negative.
+///
+/// `Dijkstra` is not recursive, and it computes distances lazily: it can be
+/// stopped at any point, more start nodes can be added with `add_start`, and
+/// several traversals can be interleaved.
+///
+/// ```
+/// use petgraph::Graph;
+/// use petgraph::visit::Dijkstra;
+///
+/// let mut graph = Graph::new();
+/// let a = graph.add_node("a");
+/// let b = graph.add_node("b");
+/// let c = graph.add_node("c");
+/// let d = graph.add_node("d");
+/// graph.extend_with_edges(&[(a, b, 2), (a, c, 1), (c, b, 3), (b, d, 1)]);
+///
+/// let mut dijkstra = Dijkstra::new(&graph, a, |e| *e.weight());
+/// let mut order = Vec::new();
+/// while let Some((nx, dist)) = dijkstra.next(&graph) {
+///     order.push((graph[nx], dist));
+///     if dist >= 2 {
+///         break;
+///     }
+/// }
+/// assert_eq!(order, vec![("a", 0), ("c", 1), ("b", 2)]);
+///
+/// // Continue where the traversal stopped.
+/// assert_eq!(dijkstra.next(&graph), Some((d, 3)));
+/// assert_eq!(dijkstra.next(&graph), None);
+/// ```
+///
+/// **Note:** The algorithm may not behave correctly if nodes are removed
+/// during iteration. It may not necessarily visit added nodes or edges.
+#[derive(Clone)]
+pub struct Dijkstra<N, K, VM, F>
+    where N: Eq + Hash,
+{
+    /// The shortest distances found so far; they are final for nodes
+    /// that are finished
+    pub scores: HashMap<N, K>,
+    /// The map of finished (settled) nodes
+    pub finished: VM,
+    visit_next: BinaryHeap<MinScored<K, N>>,
+    edge_cost: F,
+}
+
+impl<N, K, VM, F> Dijkstra<N, K, VM, F>
+    where N: Copy + Eq + Hash,
+          K: Measure + Copy,
+          VM: VisitMap<N>,
+{
+    /// Create a new **Dijkstra**, using the graph's visitor map, and put
+    /// **start** in the queue of nodes to visit.
+    pub fn new<G>(graph: G, start: N, edge_cost: F) -> Self
+        where G: IntoEdges + Visitable<NodeId=N, Map=VM>,
+              F: FnMut(G::EdgeRef) -> K,
+    {
+        let mut dijkstra = Dijkstra::empty(graph, edge_cost);
+        dijkstra.add_start(start);
+        dijkstra
+    }
+
+    /// Create a new **Dijkstra** using the graph's visitor map, and no
+    /// nodes to visit.
+    pub fn empty<G>(graph: G, edge_cost: F) -> Self
+        where G: IntoEdges + Visitable<NodeId=N, Map=VM>,
+              F: FnMut(G::EdgeRef) -> K,
+    {
+        Dijkstra {
+            scores: HashMap::new(),
+            finished: graph.visit_map(),
+            visit_next: BinaryHeap::new(),
+            edge_cost: edge_cost,
+        }
+    }
+
+    /// Clear the visit state
+    pub fn reset<G>(&mut self, graph: G)
+        where G: GraphRef + Visitable<NodeId=N, Map=VM>
+    {
+        graph.reset_map(&mut self.finished);
+        self.scores.clear();
+        self.visit_next.clear();
+    }
+
+    /// Keep the finished map and the distances of finished nodes, but clear
+    /// the queue of nodes to visit and restart the traversal from a
+    /// particular node.
+    pub fn move_to(&mut self, start: N) {
+        // Every node with a tentative distance is still in the queue; forget
+        // those distances so that paths from `start` are not cut short by them
+        for MinScored(_, node) in self.visit_next.drain() {
+            if !self.finished.is_visited(&node) {
+                self.scores.remove(&node);
+            }
+        }
+        self.add_start(start);
+    }
+
+    /// Add **start** to the queue of nodes to visit, at distance zero.
+    ///
+    /// The distances of nodes that are already finished are not updated.
+    pub fn add_start(&mut self, start: N) {
+        let zero = K::default();
+        if self.finished.is_visited(&start) {
+            return;
+        }
+        self.scores.insert(start, zero);
+        self.visit_next.push(MinScored(zero, start));
+    }
+
+    /// Return the next node in the traversal and its distance, or **None**
+    /// if the traversal is done.
+    pub fn next<G>(&mut self, graph: G) -> Option<(N, K)>
+        where G: IntoEdges<NodeId=N>,
+              F: FnMut(G::EdgeRef) -> K,
+    {
+        while let Some(MinScored(node_score, node)) = self.visit_next.pop() {
+            if !self.finished.visit(node) {
+                continue;
+            }
+            for edge in graph.edges(node) {
+                let next = edge.target();
+                if self.finished.is_visited(&next) {
+                    continue;
+                }
+                let next_score = node_score + (self.edge_cost)(edge);
+                match self.scores.entry(next) {
+                    Occupied(ent) => if next_score < *ent.get() {
+                        *ent.into_mut() = next_score;
+                    } else {
+                        continue;
+                    },
+                    Vacant(ent) => {
+                        ent.insert(next_score);
+                    }
+                }
+                self.visit_next.push(MinScored(next_score, next));
+            }
+            return Some((node, node_score));
+        }
+        None
+    }
+}
+
 /// A topological order traversal for a graph.
 ///
 /// **Note** that `Topo` only visits nodes that are not part of cycles,
@@ -447,3 +626,15 @@ impl<G> Walker<G> for Topo<G::NodeId, G::Map>
         self.next(context)
     }
 }
+
+impl<G, K, F> Walker<G> for Dijkstra<G::NodeId, K, G::Map, F>
+    where G: IntoEdges + Visitable,
+          G::NodeId: Eq + Hash,
+          F: FnMut(G::EdgeRef) -> K,
+          K: Measure + Copy,
+{
+    type Item = (G::NodeId, K);
+    fn walk_next(&mut self, context: G) -> Option<Self::Item> {
+        self.next(context)
+    }
+}
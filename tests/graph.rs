@@ -1,6 +1,6 @@
 extern crate petgraph;
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
 
 use petgraph::prelude::*;
@@ -11,6 +11,7 @@ use petgraph::{
 use petgraph as pg;
 
 use petgraph::algo::{
+    coloring,
     dominators,
     has_path_connecting,
     is_cyclic_undirected,
@@ -35,7 +36,38 @@ use petgraph::visit::{
 use petgraph::algo::{
     DfsSpace,
     dijkstra,
+    dijkstra_paths,
+    multi_source_dijkstra,
+    multi_source_bfs,
     astar,
+    floyd_warshall,
+    johnson,
+    bellman_ford,
+    NegativeCycle,
+    k_shortest_paths,
+    bidirectional_dijkstra,
+    dag_shortest_paths,
+    dag_longest_path,
+    max_flow,
+    min_cost_flow,
+    stoer_wagner_min_cut,
+    gomory_hu_tree,
+    maximum_bipartite_matching,
+    maximum_matching,
+    kuhn_munkres,
+    bipartite_coloring,
+    articulation_points,
+    bridges,
+    biconnected_components,
+    eulerian_circuit,
+    eulerian_path,
+    EulerianError,
+    simple_cycles,
+    cycle_basis,
+    girth,
+    shortest_cycle_through,
+    transitive_closure,
+    transitive_reduction,
 };
 
 use petgraph::dot::{
@@ -343,6 +375,1027 @@ fn dijk() {
     assert_eq!(scores[&c], 9);
 }
 
+#[test]
+fn dijk_paths() {
+    let mut g = Graph::new_undirected();
+    let a = g.add_node("A");
+    let b = g.add_node("B");
+    let c = g.add_node("C");
+    let d = g.add_node("D");
+    let e = g.add_node("E");
+    let f = g.add_node("F");
+    let z = g.add_node("Z");
+    g.add_edge(a, b, 7);
+    let ca = g.add_edge(c, a, 9);
+    g.add_edge(a, d, 14);
+    g.add_edge(b, c, 10);
+    let dc = g.add_edge(d, c, 2);
+    let de = g.add_edge(d, e, 9);
+    g.add_edge(b, f, 15);
+    let cf = g.add_edge(c, f, 11);
+    g.add_edge(e, f, 6);
+
+    let paths = dijkstra_paths(&g, a, None, |e| *e.weight());
+    assert_eq!(paths.scores(), &dijkstra(&g, a, None, |e| *e.weight()));
+    assert_eq!(paths.predecessor(a), None);
+    assert_eq!(paths.predecessor(e), Some((d, de)));
+    assert_eq!(paths.path_to(a), Some(vec![a]));
+    assert_eq!(paths.edge_path_to(a), Some(vec![]));
+    assert_eq!(paths.path_to(e), Some(vec![a, c, d, e]));
+    assert_eq!(paths.edge_path_to(e), Some(vec![ca, dc, de]));
+    assert_eq!(paths.path_to(f), Some(vec![a, c, f]));
+    assert_eq!(paths.edge_path_to(f), Some(vec![ca, cf]));
+    assert_eq!(paths.path_to(z), None);
+    assert_eq!(paths.edge_path_to(z), None);
+
+    // the path agrees with astar
+    for end in g.node_indices() {
+        let astar_path = astar(&g, a, |finish| finish == end, |e| *e.weight(), |_| 0);
+        assert_eq!(paths.score(end).cloned(), astar_path.as_ref().map(|t| t.0));
+        let path = paths.path_to(end);
+        assert_eq!(path.as_ref().map(|p| p.len()), astar_path.map(|t| t.1.len()));
+    }
+
+    let paths = dijkstra_paths(&g, a, Some(c), |e| *e.weight());
+    assert_eq!(paths.path_to(c), Some(vec![a, c]));
+}
+
+#[test]
+fn multi_source() {
+    let mut g = Graph::new_undirected();
+    let a = g.add_node("A");
+    let b = g.add_node("B");
+    let c = g.add_node("C");
+    let d = g.add_node("D");
+    let e = g.add_node("E");
+    let f = g.add_node("F");
+    let z = g.add_node("Z");
+    g.add_edge(a, b, 7);
+    g.add_edge(c, a, 9);
+    g.add_edge(a, d, 14);
+    g.add_edge(b, c, 10);
+    g.add_edge(d, c, 2);
+    g.add_edge(d, e, 9);
+    g.add_edge(b, f, 15);
+    g.add_edge(c, f, 11);
+    g.add_edge(e, f, 6);
+
+    let from_a = dijkstra(&g, a, None, |e| *e.weight());
+    let from_e = dijkstra(&g, e, None, |e| *e.weight());
+    let nearest = multi_source_dijkstra(&g, vec![(a, 0), (e, 0)], |e| *e.weight());
+    assert_eq!(nearest.len(), 6);
+    assert!(!nearest.contains_key(&z));
+    for (n, &(score, source)) in &nearest {
+        assert_eq!(score, ::std::cmp::min(from_a[n], from_e[n]));
+        let from_source = if source == a { &from_a } else { &from_e };
+        assert_eq!(score, from_source[n]);
+    }
+    assert_eq!(nearest[&c], (9, a));
+    assert_eq!(nearest[&d], (9, e));
+    assert_eq!(nearest[&f], (6, e));
+
+    // initial costs shift the boundary between the two sources
+    let nearest = multi_source_dijkstra(&g, vec![(a, 0), (e, 5)], |e| *e.weight());
+    assert_eq!(nearest[&e], (5, e));
+    assert_eq!(nearest[&d], (11, a));
+    assert_eq!(nearest[&f], (11, e));
+
+    // a start that is reached more cheaply from another start
+    let nearest = multi_source_dijkstra(&g, vec![(a, 0), (b, 10), (a, 3)], |e| *e.weight());
+    assert_eq!(nearest[&a], (0, a));
+    assert_eq!(nearest[&b], (7, a));
+
+    assert_eq!(multi_source_dijkstra(&g, vec![], |e| *e.weight()), HashMap::new());
+
+    let nearest = multi_source_bfs(&g, vec![a, e]);
+    assert_eq!(nearest[&a], (0, a));
+    assert_eq!(nearest[&b], (1, a));
+    assert_eq!(nearest[&c], (1, a));
+    assert_eq!(nearest[&f], (1, e));
+    assert_eq!(nearest[&d].0, 1);
+    assert!(!nearest.contains_key(&z));
+
+    let order: Vec<_> = Bfs::with_starts(&g, vec![z, a, e]).iter(&g).collect();
+    assert_eq!(order.len(), 7);
+    assert_eq!(&order[..3], &[z, a, e]);
+    let depth = |n| if n == z { 0 } else { nearest[&n].0 };
+    assert!(order.windows(2).all(|w| depth(w[0]) <= depth(w[1])));
+}
+
+#[test]
+fn dag_paths() {
+    let mut g = Graph::new();
+    let a = g.add_node("A");
+    let b = g.add_node("B");
+    let c = g.add_node("C");
+    let d = g.add_node("D");
+    let e = g.add_node("E");
+    let z = g.add_node("Z");
+    g.extend_with_edges(&[
+        (a, b, 2),
+        (a, c, 6),
+        (b, c, 3),
+        (b, d, -1),
+        (c, e, 2),
+        (d, e, 4),
+        (z, a, 1),
+        (z, e, -10),
+    ]);
+
+    let (dist, pred) = dag_shortest_paths(&g, a, |e| *e.weight()).unwrap();
+    let expected: HashMap<_, _> = vec![(a, 0), (b, 2), (c, 5), (d, 1), (e, 5)].into_iter().collect();
+    assert_eq!(dist, expected);
+    assert_eq!(pred[&b], a);
+    assert_eq!(pred[&c], b);
+    assert_eq!(pred[&d], b);
+    assert!(!pred.contains_key(&a));
+    assert!(!pred.contains_key(&z));
+
+    let (dist, pred) = dag_longest_path(&g, a, |e| *e.weight()).unwrap();
+    let expected: HashMap<_, _> = vec![(a, 0), (b, 2), (c, 6), (d, 1), (e, 8)].into_iter().collect();
+    assert_eq!(dist, expected);
+    assert_eq!(pred[&e], c);
+    assert_eq!(pred[&c], a);
+
+    let (dist, _) = dag_shortest_paths(&g, z, |e| *e.weight()).unwrap();
+    assert_eq!(dist[&e], -10);
+    let (dist, _) = dag_longest_path(&g, z, |e| *e.weight()).unwrap();
+    assert_eq!(dist[&e], 9);
+
+    // a cycle anywhere in the graph is an error
+    g.add_edge(e, d, 0);
+    assert!(dag_shortest_paths(&g, z, |e| *e.weight()).is_err());
+    assert!(dag_longest_path(&g, a, |e| *e.weight()).is_err());
+    let mut h = Graph::<(), i32>::new();
+    let x = h.add_node(());
+    h.add_edge(x, x, 1);
+    assert_eq!(dag_shortest_paths(&h, x, |e| *e.weight()).map(|_| ()).map_err(|c| c.node_id()),
+               Err(x));
+}
+
+#[test]
+fn test_max_flow() {
+    // example from https://en.wikipedia.org/wiki/Dinic%27s_algorithm
+    let mut g = Graph::new();
+    let s = g.add_node("s");
+    let a = g.add_node("1");
+    let b = g.add_node("2");
+    let c = g.add_node("3");
+    let d = g.add_node("4");
+    let t = g.add_node("t");
+    g.extend_with_edges(&[
+        (s, a, 10),
+        (s, b, 10),
+        (a, b, 2),
+        (a, c, 4),
+        (a, d, 8),
+        (b, d, 9),
+        (d, c, 6),
+        (c, t, 10),
+        (d, t, 10),
+    ]);
+    let flow = max_flow(&g, s, t, |e| *e.weight());
+    assert_eq!(flow.value(), 19);
+    assert_eq!(flow.flows().len(), g.edge_count());
+    for edge in g.edge_references() {
+        assert!(flow.flow(edge.id()).unwrap() <= *edge.weight());
+    }
+    assert_eq!(flow.min_cut(), &[s, b]);
+
+    // no path to the sink
+    let flow = max_flow(&g, t, s, |e| *e.weight());
+    assert_eq!(flow.value(), 0);
+    assert_eq!(flow.min_cut(), &[t]);
+    assert!(flow.flows().values().all(|&f| f == 0));
+
+    // capacities from a closure
+    let flow = max_flow(&g, s, t, |_| 1);
+    assert_eq!(flow.value(), 2);
+
+    // undirected edges carry flow either way
+    let mut u = Graph::new_undirected();
+    let s = u.add_node("s");
+    let a = u.add_node("a");
+    let b = u.add_node("b");
+    let t = u.add_node("t");
+    u.extend_with_edges(&[(s, a, 1.5), (b, s, 2.), (a, b, 1.), (t, a, 3.), (b, t, 1.)]);
+    let flow = max_flow(&u, s, t, |e| *e.weight());
+    assert_eq!(flow.value(), 3.5);
+    assert_eq!(flow.min_cut(), &[s]);
+}
+
+#[test]
+fn test_min_cost_flow() {
+    // edge weights are (capacity, cost)
+    let mut g = Graph::new();
+    let s = g.add_node("s");
+    let a = g.add_node("a");
+    let b = g.add_node("b");
+    let c = g.add_node("c");
+    let t = g.add_node("t");
+    let sa = g.add_edge(s, a, (4, 2));
+    let sb = g.add_edge(s, b, (2, 2));
+    let ab = g.add_edge(a, b, (2, 1));
+    let ac = g.add_edge(a, c, (3, 6));
+    let bc = g.add_edge(b, c, (5, -2));
+    let bt = g.add_edge(b, t, (1, 7));
+    let ct = g.add_edge(c, t, (4, 1));
+
+    let flow = min_cost_flow(&g, s, t, |e| e.weight().0, |e| e.weight().1).unwrap();
+    assert_eq!(flow.value(), 5);
+    assert_eq!(flow.value(), max_flow(&g, s, t, |e| e.weight().0).value());
+    assert_eq!(flow.cost(), 23);
+    assert_eq!(flow.flow(sa), Some(3));
+    assert_eq!(flow.flow(sb), Some(2));
+    assert_eq!(flow.flow(ab), Some(2));
+    assert_eq!(flow.flow(ac), Some(1));
+    assert_eq!(flow.flow(bc), Some(3));
+    assert_eq!(flow.flow(bt), Some(1));
+    assert_eq!(flow.flow(ct), Some(4));
+    assert_eq!(flow.flows().len(), g.edge_count());
+
+    // no path to the sink
+    let flow = min_cost_flow(&g, t, s, |e| e.weight().0, |e| e.weight().1).unwrap();
+    assert_eq!(flow.value(), 0);
+    assert_eq!(flow.cost(), 0);
+
+    // a negative cycle through edges of zero capacity doesn't matter
+    let ca = g.add_edge(c, a, (0, -5));
+    assert!(min_cost_flow(&g, s, t, |e| e.weight().0, |e| e.weight().1).is_ok());
+    g[ca].0 = 1;
+    let cycle = min_cost_flow(&g, s, t, |e| e.weight().0, |e| e.weight().1).unwrap_err();
+    assert_eq!(cycle.nodes().len(), 3);
+    let mut edges = cycle.edges().to_vec();
+    edges.sort();
+    assert_eq!(edges, vec![ab, bc, ca]);
+}
+
+#[test]
+fn test_stoer_wagner_min_cut() {
+    // example from the Stoer–Wagner paper
+    let mut g = Graph::new_undirected();
+    let v: Vec<_> = (1..9).map(|i| g.add_node(i)).collect();
+    g.extend_with_edges(&[
+        (v[0], v[1], 2),
+        (v[0], v[4], 3),
+        (v[1], v[2], 3),
+        (v[1], v[4], 2),
+        (v[1], v[5], 2),
+        (v[2], v[3], 4),
+        (v[2], v[6], 2),
+        (v[3], v[6], 2),
+        (v[3], v[7], 2),
+        (v[4], v[5], 3),
+        (v[5], v[6], 1),
+        (v[6], v[7], 3),
+    ]);
+    let (weight, side) = stoer_wagner_min_cut(&g, |e| *e.weight()).unwrap();
+    assert_eq!(weight, 4);
+    let side: HashSet<_> = side.into_iter().map(|n| g[n]).collect();
+    let other: HashSet<_> = (1..9).filter(|i| !side.contains(i)).collect();
+    let expected: HashSet<_> = vec![3, 4, 7, 8].into_iter().collect();
+    assert!(side == expected || other == expected);
+
+    // with unit weights from a closure
+    let (weight, _) = stoer_wagner_min_cut(&g, |_| 1).unwrap();
+    assert_eq!(weight, 2);
+
+    // directions are ignored, and a disconnected graph has an empty cut
+    let mut h = Graph::new();
+    let a = h.add_node(());
+    let b = h.add_node(());
+    let c = h.add_node(());
+    h.add_edge(a, b, 1.5);
+    h.add_edge(b, a, 1.);
+    h.add_edge(b, b, 4.);
+    let (weight, side) = stoer_wagner_min_cut(&h, |e| *e.weight()).unwrap();
+    assert_eq!(weight, 0.);
+    assert!(side == vec![c] || side == vec![a, b]);
+    h.add_edge(c, b, 3.);
+    let (weight, side) = stoer_wagner_min_cut(&h, |e| *e.weight()).unwrap();
+    assert_eq!(weight, 2.5);
+    assert!(side == vec![a] || side == vec![b, c]);
+}
+
+#[test]
+fn test_gomory_hu_tree() {
+    // example from https://en.wikipedia.org/wiki/Gomory%E2%80%93Hu_tree
+    let mut g = Graph::new_undirected();
+    let v: Vec<_> = (0..6).map(|i| g.add_node(i)).collect();
+    g.extend_with_edges(&[
+        (v[0], v[1], 1),
+        (v[0], v[2], 7),
+        (v[1], v[2], 1),
+        (v[1], v[3], 3),
+        (v[1], v[4], 2),
+        (v[2], v[4], 4),
+        (v[3], v[4], 1),
+        (v[3], v[5], 6),
+        (v[4], v[5], 2),
+    ]);
+    let tree = gomory_hu_tree(&g, |e| *e.weight());
+    let mut weights: Vec<_> = tree.tree().edge_references().map(|e| *e.weight()).collect();
+    weights.sort();
+    assert_eq!(weights, vec![6, 6, 7, 8, 8]);
+    for &a in &v {
+        for &b in &v {
+            if a == b {
+                assert_eq!(tree.min_cut(a, b), None);
+                continue;
+            }
+            let (value, side) = tree.min_cut(a, b).unwrap();
+            assert_eq!(value, max_flow(&g, a, b, |e| *e.weight()).value());
+            assert!(side.contains(&a) && !side.contains(&b));
+        }
+    }
+    assert_eq!(tree.min_cut(v[5], v[0]).map(|cut| cut.0), Some(6));
+
+    let empty = Graph::<(), u32, Undirected>::new_undirected();
+    assert_eq!(gomory_hu_tree(&empty, |e| *e.weight()).tree().node_count(), 0);
+}
+
+#[test]
+fn test_maximum_bipartite_matching() {
+    let mut g = Graph::new_undirected();
+    let l: Vec<_> = (0..5).map(|i| g.add_node(i)).collect();
+    let r: Vec<_> = (5..10).map(|i| g.add_node(i)).collect();
+    g.extend_with_edges(&[
+        (l[0], r[0]),
+        (l[0], r[1]),
+        (r[0], l[1]),
+        (l[2], r[0]),
+        (l[2], r[2]),
+        (l[2], r[3]),
+        (r[1], l[3]),
+        (l[3], r[4]),
+        (l[4], r[1]),
+        (l[4], r[4]),
+        // edges within one side are ignored
+        (l[1], l[4]),
+        (r[2], r[3]),
+    ]);
+    let matching = maximum_bipartite_matching(&g, l.clone());
+    assert_eq!(matching.len(), 4);
+    let mut matched = HashSet::new();
+    for &e in &matching {
+        let (a, b) = g.edge_endpoints(e).unwrap();
+        assert!(g[a] < 5 && g[b] >= 5 || g[a] >= 5 && g[b] < 5);
+        assert!(matched.insert(a) && matched.insert(b));
+    }
+    // the right side gives the same size
+    assert_eq!(maximum_bipartite_matching(&g, r.clone()).len(), 4);
+
+    // adding an edge to the only unmatched right node makes it perfect
+    let missing = r.iter().cloned().find(|n| !matched.contains(n)).unwrap();
+    let unmatched = l.iter().cloned().find(|n| !matched.contains(n)).unwrap();
+    g.add_edge(unmatched, missing, ());
+    assert_eq!(maximum_bipartite_matching(&g, l.clone()).len(), 5);
+
+    // in a directed graph, only edges leaving the left side count
+    let mut d = Graph::<(), ()>::new();
+    let a = d.add_node(());
+    let b = d.add_node(());
+    let c = d.add_node(());
+    d.add_edge(b, a, ());
+    let ac = d.add_edge(a, c, ());
+    assert_eq!(maximum_bipartite_matching(&d, vec![a]), vec![ac]);
+    assert_eq!(maximum_bipartite_matching(&d, vec![c]), vec![]);
+}
+
+#[test]
+fn test_maximum_matching() {
+    // two five-cycles joined by an edge, each with a tail: finding a perfect
+    // matching needs the blossoms to be contracted
+    let mut g = Graph::new_undirected();
+    let n: Vec<_> = (0..12).map(|i| g.add_node(i)).collect();
+    g.extend_with_edges(&[
+        (n[0], n[1]), (n[1], n[2]), (n[2], n[3]), (n[3], n[4]), (n[4], n[0]),
+        (n[5], n[6]), (n[6], n[7]), (n[7], n[8]), (n[8], n[9]), (n[9], n[5]),
+        (n[2], n[7]),
+        (n[0], n[10]),
+        (n[5], n[11]),
+    ]);
+    {
+        let matching = maximum_matching(&g);
+        assert_eq!(matching.len(), 6);
+        assert!(matching.is_perfect());
+        assert_eq!(matching.mate(n[10]), Some(n[0]));
+        assert_eq!(matching.mate(n[11]), Some(n[5]));
+        let mut matched = HashSet::new();
+        for e in matching.edges() {
+            let (a, b) = g.edge_endpoints(e).unwrap();
+            assert_eq!(matching.mate(a), Some(b));
+            assert_eq!(matching.mate(b), Some(a));
+            assert!(matched.insert(a) && matched.insert(b));
+        }
+        assert_eq!(matched.len(), 12);
+    }
+
+    // an odd number of nodes can't all be matched, and self loops don't count
+    let c = g.add_node(12);
+    g.add_edge(c, c, ());
+    let matching = maximum_matching(&g);
+    assert_eq!(matching.len(), 6);
+    assert!(!matching.is_perfect());
+    assert_eq!(matching.mate(c), None);
+
+    let empty = Graph::<(), ()>::new();
+    let matching = maximum_matching(&empty);
+    assert!(matching.is_empty());
+    assert!(matching.is_perfect());
+}
+
+#[test]
+fn test_kuhn_munkres() {
+    // a square cost matrix where the cheapest entry of each row conflicts
+    let costs = [
+        [4, 1, 3],
+        [2, 0, 5],
+        [3, 2, 2],
+    ];
+    let mut g = Graph::new_undirected();
+    let l: Vec<_> = (0..3).map(|i| g.add_node(i)).collect();
+    let r: Vec<_> = (3..6).map(|i| g.add_node(i)).collect();
+    for i in 0..3 {
+        for j in 0..3 {
+            g.add_edge(l[i], r[j], costs[i][j]);
+        }
+    }
+    let (cost, matching) = kuhn_munkres(&g, l.clone(), |e| *e.weight()).unwrap();
+    assert_eq!(cost, 5);
+    let pairs: Vec<_> = matching.iter().map(|&e| g.edge_endpoints(e).unwrap()).collect();
+    assert_eq!(pairs, vec![(l[0], r[1]), (l[1], r[0]), (l[2], r[2])]);
+    // either side can be given as the left nodes
+    let (cost, matching) = kuhn_munkres(&g, r.clone(), |e| *e.weight()).unwrap();
+    assert_eq!(cost, 5);
+    assert_eq!(matching.len(), 3);
+
+    // negative costs, and the cheaper of two parallel edges
+    let cheap = g.add_edge(l[2], r[0], -4);
+    let (cost, matching) = kuhn_munkres(&g, l.clone(), |e| *e.weight()).unwrap();
+    assert_eq!(cost, -1);
+    assert_eq!(matching[2], cheap);
+
+    // with an extra left node, one left node is left out
+    let x = g.add_node(6);
+    g.add_edge(x, r[1], -10);
+    let mut left = l.clone();
+    left.push(x);
+    let (cost, matching) = kuhn_munkres(&g, left, |e| *e.weight()).unwrap();
+    assert_eq!(cost, -11);
+    assert_eq!(matching.len(), 3);
+
+    // the smaller side must be matched completely
+    let mut h = Graph::<(), f64>::new();
+    let a = h.add_node(());
+    let b = h.add_node(());
+    let c = h.add_node(());
+    let d = h.add_node(());
+    h.add_edge(a, c, 1.);
+    h.add_edge(a, d, 2.);
+    assert_eq!(kuhn_munkres(&h, vec![a, b], |e| *e.weight()), None);
+    let bd = h.add_edge(b, d, 0.5);
+    let ac = h.find_edge(a, c).unwrap();
+    assert_eq!(kuhn_munkres(&h, vec![a, b], |e| *e.weight()), Some((1.5, vec![ac, bd])));
+    assert_eq!(kuhn_munkres(&h, vec![], |e| *e.weight()), Some((0., vec![])));
+}
+
+#[test]
+fn test_bipartite_coloring() {
+    // a hexagon with a chord between opposite corners is bipartite
+    let mut g = Graph::new_undirected();
+    let n: Vec<_> = (0..6).map(|i| g.add_node(i)).collect();
+    g.extend_with_edges(&[
+        (n[0], n[1]), (n[1], n[2]), (n[2], n[3]), (n[3], n[4]), (n[4], n[5]), (n[5], n[0]),
+        (n[0], n[3]),
+    ]);
+    let coloring = bipartite_coloring(&g).unwrap();
+    assert_eq!(coloring, vec![false, true, false, true, false, true]);
+
+    // a chord between corners two apart closes a triangle
+    g.add_edge(n[1], n[5], ());
+    let cycle = bipartite_coloring(&g).unwrap_err();
+    let mut nodes = cycle.nodes().to_vec();
+    nodes.sort();
+    assert_eq!(nodes, vec![n[0], n[1], n[5]]);
+
+    // a self loop is a cycle of length one
+    let mut h = Graph::<(), (), Undirected>::new_undirected();
+    let a = h.add_node(());
+    let b = h.add_node(());
+    let c = h.add_node(());
+    h.add_edge(a, b, ());
+    assert_eq!(bipartite_coloring(&h), Ok(vec![false, true, false]));
+    h.add_edge(c, c, ());
+    assert_eq!(bipartite_coloring(&h).unwrap_err().nodes(), &[c]);
+
+    // edges of a directed graph are followed both ways
+    let mut d = Graph::<(), ()>::new();
+    let a = d.add_node(());
+    let b = d.add_node(());
+    let c = d.add_node(());
+    d.extend_with_edges(&[(a, b), (c, a)]);
+    assert_eq!(bipartite_coloring(&d), Ok(vec![false, true, true]));
+    d.add_edge(b, c, ());
+    let cycle = bipartite_coloring(&d).unwrap_err();
+    let mut nodes = cycle.nodes().to_vec();
+    nodes.sort();
+    assert_eq!(nodes, vec![a, b, c]);
+}
+
+/// Check that `colors` is a proper coloring of `g` with `n_colors` colors.
+fn assert_coloring<N, E, Ty>(g: &Graph<N, E, Ty>, colors: &HashMap<NodeIndex, usize>, n_colors: usize)
+    where Ty: EdgeType,
+{
+    assert_eq!(colors.len(), g.node_count());
+    for e in g.edge_references() {
+        if e.source() != e.target() {
+            assert!(colors[&e.source()] != colors[&e.target()]);
+        }
+    }
+    let used: HashSet<_> = colors.values().cloned().collect();
+    assert_eq!(used, (0..n_colors).collect());
+}
+
+#[test]
+fn test_coloring() {
+    // the crown graph: a complete bipartite graph minus a perfect matching,
+    // which greedy coloring handles badly in the interleaved order
+    let mut g = Graph::new_undirected();
+    let a: Vec<_> = (0..4).map(|i| g.add_node(i)).collect();
+    let b: Vec<_> = (0..4).map(|i| g.add_node(i)).collect();
+    for i in 0..4 {
+        for j in 0..4 {
+            if i != j {
+                g.add_edge(a[i], b[j], ());
+            }
+        }
+    }
+    let interleaved: Vec<_> = (0..4).flat_map(|i| vec![a[i], b[i]]).collect();
+    let (colors, n_colors) = coloring::greedy_coloring(&g, interleaved);
+    assert_coloring(&g, &colors, n_colors);
+    assert_eq!(n_colors, 4);
+    let (colors, n_colors) = coloring::greedy_coloring(&g, a.clone());
+    assert_coloring(&g, &colors, n_colors);
+    assert_eq!(n_colors, 2);
+    let (colors, n_colors) = coloring::dsatur_coloring(&g);
+    assert_coloring(&g, &colors, n_colors);
+    assert_eq!(n_colors, 2);
+    let (colors, n_colors) = coloring::welsh_powell_coloring(&g);
+    assert_coloring(&g, &colors, n_colors);
+
+    // a directed triangle with a self loop and a parallel edge needs three
+    let mut d = Graph::<(), ()>::new();
+    let x = d.add_node(());
+    let y = d.add_node(());
+    let z = d.add_node(());
+    d.extend_with_edges(&[(x, y), (y, z), (z, x), (x, x), (y, x)]);
+    for &(ref colors, n_colors) in &[
+        coloring::greedy_coloring(&d, vec![]),
+        coloring::dsatur_coloring(&d),
+        coloring::welsh_powell_coloring(&d),
+    ] {
+        assert_coloring(&d, colors, n_colors);
+        assert_eq!(n_colors, 3);
+    }
+
+    // nodes without edges all get the first color
+    let mut e = Graph::<(), ()>::new();
+    e.add_node(());
+    e.add_node(());
+    assert_eq!(coloring::dsatur_coloring(&e).1, 1);
+    assert_eq!(coloring::welsh_powell_coloring(&Graph::<(), ()>::new()).1, 0);
+}
+
+#[test]
+fn test_edge_coloring() {
+    // the Petersen graph needs four colors, one more than its degree
+    let mut g = Graph::new_undirected();
+    let outer: Vec<_> = (0..5).map(|i| g.add_node(i)).collect();
+    let inner: Vec<_> = (5..10).map(|i| g.add_node(i)).collect();
+    for i in 0..5 {
+        g.add_edge(outer[i], outer[(i + 1) % 5], ());
+        g.add_edge(outer[i], inner[i], ());
+        g.add_edge(inner[i], inner[(i + 2) % 5], ());
+    }
+    let (colors, n_colors) = coloring::edge_coloring(&g);
+    assert_eq!(colors.len(), 15);
+    assert_eq!(n_colors, 4);
+    for a in g.node_indices() {
+        let at: HashSet<_> = g.edges(a).map(|e| colors[&e.id()]).collect();
+        assert_eq!(at.len(), 3);
+    }
+}
+
+#[test]
+fn test_biconnected() {
+    //   a - b       f
+    //   | / |       |
+    //   c - d - e - g = h    i
+    let mut g = Graph::new_undirected();
+    let a = g.add_node("a");
+    let b = g.add_node("b");
+    let c = g.add_node("c");
+    let d = g.add_node("d");
+    let e = g.add_node("e");
+    let f = g.add_node("f");
+    let gg = g.add_node("g");
+    let h = g.add_node("h");
+    g.add_node("i");
+    g.extend_with_edges(&[(a, b), (a, c), (b, c), (b, d), (c, d)]);
+    let de = g.add_edge(d, e, ());
+    let eg = g.add_edge(e, gg, ());
+    let fg = g.add_edge(f, gg, ());
+    g.add_edge(gg, h, ());
+    g.add_edge(h, gg, ());
+    g.add_edge(h, h, ());
+
+    assert_eq!(articulation_points(&g), vec![d, e, gg]);
+    // the parallel edges between g and h are not bridges
+    assert_eq!(bridges(&g), vec![de, eg, fg]);
+
+    let mut components: Vec<Vec<_>> = biconnected_components(&g).into_iter().map(|c| {
+        let mut nodes: Vec<_> = c.iter().flat_map(|&e| {
+            let (x, y) = g.edge_endpoints(e).unwrap();
+            vec![g[x], g[y]]
+        }).collect();
+        nodes.sort();
+        nodes.dedup();
+        nodes
+    }).collect();
+    components.sort();
+    assert_eq!(components, vec![
+        vec!["a", "b", "c", "d"],
+        vec!["d", "e"],
+        vec!["e", "g"],
+        vec!["f", "g"],
+        vec!["g", "h"],
+    ]);
+
+    // a long path does not overflow the stack
+    let mut p = Graph::<(), (), Undirected>::with_capacity(0, 0);
+    let mut last = p.add_node(());
+    for _ in 0..100_000 {
+        let next = p.add_node(());
+        p.add_edge(last, next, ());
+        last = next;
+    }
+    assert_eq!(articulation_points(&p).len(), 99_999);
+    assert_eq!(bridges(&p).len(), 100_000);
+    assert_eq!(biconnected_components(&p).len(), 100_000);
+}
+
+#[test]
+fn test_eulerian() {
+    // the seven bridges of Königsberg
+    let mut g = Graph::new_undirected();
+    let island = g.add_node("island");
+    let north = g.add_node("north");
+    let south = g.add_node("south");
+    let east = g.add_node("east");
+    g.extend_with_edges(&[
+        (island, north), (island, north),
+        (island, south), (island, south),
+        (island, east), (north, east), (south, east),
+    ]);
+    let unbalanced = Err(EulerianError::Unbalanced(vec![island, north, south, east]));
+    assert_eq!(eulerian_circuit(&g), unbalanced);
+    assert_eq!(eulerian_path(&g), unbalanced);
+
+    // without the bridge between the island and the east bank, there is a
+    // walk between the north and south banks over every other bridge
+    let ie = g.find_edge(island, east).unwrap();
+    g.remove_edge(ie);
+    assert_eq!(eulerian_circuit(&g), Err(EulerianError::Unbalanced(vec![north, south])));
+    let path = eulerian_path(&g).unwrap();
+    assert_eq!(path.len(), 6);
+    let mut node = north;
+    for &e in &path {
+        let (a, b) = g.edge_endpoints(e).unwrap();
+        assert!(a == node || b == node);
+        node = if a == node { b } else { a };
+    }
+    assert_eq!(node, south);
+
+    // a self loop fits anywhere, but a separate triangle can't be reached
+    let l = g.add_edge(north, north, ());
+    assert!(eulerian_path(&g).unwrap().contains(&l));
+    let x = g.add_node("x");
+    let y = g.add_node("y");
+    let z = g.add_node("z");
+    g.extend_with_edges(&[(x, y), (y, z), (z, x)]);
+    assert_eq!(eulerian_path(&g), Err(EulerianError::Disconnected));
+
+    // in a directed graph, the path goes from the node with an extra
+    // outgoing edge to the one with an extra incoming edge
+    let mut d = Graph::<(), ()>::new();
+    let a = d.add_node(());
+    let b = d.add_node(());
+    let c = d.add_node(());
+    let ab = d.add_edge(a, b, ());
+    let bc = d.add_edge(b, c, ());
+    let cb = d.add_edge(c, b, ());
+    let ba = d.add_edge(b, a, ());
+    let bc2 = d.add_edge(b, c, ());
+    assert_eq!(eulerian_circuit(&d), Err(EulerianError::Unbalanced(vec![b, c])));
+    assert_eq!(eulerian_path(&d), Ok(vec![bc, cb, ba, ab, bc2]));
+    // two nodes with extra outgoing edges
+    d.add_edge(a, c, ());
+    assert_eq!(eulerian_path(&d), Err(EulerianError::Unbalanced(vec![a, b, c])));
+
+    let empty = Graph::<(), ()>::new();
+    assert_eq!(eulerian_circuit(&empty), Ok(vec![]));
+    assert_eq!(eulerian_path(&empty), Ok(vec![]));
+}
+
+#[test]
+fn test_simple_cycles() {
+    // the complete directed graph on four nodes has 6 cycles of length two,
+    // 8 of length three and 6 of length four
+    let mut g = Graph::<(), ()>::new();
+    let n: Vec<_> = (0..4).map(|_| g.add_node(())).collect();
+    for &a in &n {
+        for &b in &n {
+            if a != b {
+                g.add_edge(a, b, ());
+            }
+        }
+    }
+    let cycles: Vec<_> = simple_cycles(&g).collect();
+    assert_eq!(cycles.len(), 20);
+    let distinct: HashSet<_> = cycles.iter().cloned().collect();
+    assert_eq!(distinct.len(), 20);
+    for cycle in &cycles {
+        assert_eq!(cycle.iter().min(), Some(&cycle[0]));
+        let nodes: HashSet<_> = cycle.iter().collect();
+        assert_eq!(nodes.len(), cycle.len());
+    }
+    for k in 2..5 {
+        assert_eq!(cycles.iter().filter(|c| c.len() == k).count(), [6, 8, 6][k - 2]);
+    }
+    assert_eq!(simple_cycles(&g).take(5).count(), 5);
+}
+
+#[test]
+fn test_cycle_basis() {
+    // a triangle with a doubled edge and a self loop, a separate square, and
+    // a tree hanging off it
+    let mut g = Graph::<_, (), Undirected>::new_undirected();
+    let n: Vec<_> = (0..9).map(|i| g.add_node(i)).collect();
+    g.extend_with_edges(&[
+        (n[0], n[1]), (n[1], n[2]), (n[2], n[0]), (n[1], n[2]), (n[0], n[0]),
+        (n[3], n[4]), (n[4], n[5]), (n[5], n[6]), (n[6], n[3]),
+        (n[6], n[7]), (n[7], n[8]),
+    ]);
+    let mut basis = cycle_basis(&g);
+    // |E| - |V| + components
+    assert_eq!(basis.len(), 11 - 9 + 2);
+    for cycle in &mut basis {
+        for i in 0..cycle.len() {
+            assert!(g.find_edge(cycle[i], cycle[(i + 1) % cycle.len()]).is_some());
+        }
+        // compare cycles by their nodes
+        cycle.sort();
+    }
+    basis.sort();
+    // both edges between n[1] and n[2] close a triangle with the forest
+    assert_eq!(basis, vec![
+        vec![n[0]],
+        vec![n[0], n[1], n[2]],
+        vec![n[0], n[1], n[2]],
+        vec![n[3], n[4], n[5], n[6]],
+    ]);
+
+    assert_eq!(shortest_cycle_through(&g, n[0]), Some(vec![n[0]]));
+    assert_eq!(shortest_cycle_through(&g, n[1]).map(|c| c.len()), Some(2));
+    assert_eq!(shortest_cycle_through(&g, n[4]).map(|c| c.len()), Some(4));
+    assert_eq!(shortest_cycle_through(&g, n[7]), None);
+    assert_eq!(girth(&g), Some(1));
+
+    // directions are ignored
+    let mut d = Graph::<(), ()>::new();
+    let a = d.add_node(());
+    let b = d.add_node(());
+    let c = d.add_node(());
+    d.extend_with_edges(&[(a, b), (b, c)]);
+    assert_eq!(girth(&d), None);
+    assert_eq!(cycle_basis(&d).len(), 0);
+    d.add_edge(a, c, ());
+    assert_eq!(girth(&d), Some(3));
+    assert_eq!(cycle_basis(&d).len(), 1);
+}
+
+#[test]
+fn test_transitive() {
+    use petgraph::csr::Csr;
+
+    // a package depending on both a library and the library's own dependency
+    let mut g = Graph::<_, ()>::new();
+    let app = g.add_node("app");
+    let lib = g.add_node("lib");
+    let log = g.add_node("log");
+    let core = g.add_node("core");
+    let test = g.add_node("test");
+    g.extend_with_edges(&[
+        (app, lib), (app, log), (app, core), (lib, log), (log, core), (lib, core),
+        (test, core), (test, core),
+    ]);
+
+    assert_eq!(transitive_reduction(&g),
+               Ok(vec![(app, lib), (lib, log), (log, core), (test, core)]));
+    assert_eq!(transitive_closure(&g),
+               Ok(vec![(app, lib), (app, log), (app, core), (lib, log), (lib, core),
+                       (log, core), (test, core)]));
+
+    // a reduction gives the same closure
+    let reduced = Graph::<(), ()>::from_edges(&transitive_reduction(&g).unwrap());
+    assert_eq!(transitive_closure(&reduced).unwrap().len(), 7);
+    assert_eq!(transitive_reduction(&reduced).unwrap().len(), reduced.edge_count());
+
+    // a Csr gives its nodes as plain indices
+    let csr = Csr::<(), ()>::from_sorted_edges(&[(0, 1), (0, 2), (1, 2), (2, 3)]).unwrap();
+    assert_eq!(transitive_reduction(&csr), Ok(vec![(0, 1), (1, 2), (2, 3)]));
+    assert_eq!(transitive_closure(&csr),
+               Ok(vec![(0, 1), (0, 2), (0, 3), (1, 2), (1, 3), (2, 3)]));
+
+    // cycles and self loops are rejected
+    g.add_edge(core, app, ());
+    assert!(transitive_closure(&g).is_err());
+    assert!(transitive_reduction(&g).is_err());
+    let mut g = Graph::<(), ()>::new();
+    let a = g.add_node(());
+    g.add_edge(a, a, ());
+    assert_eq!(transitive_closure(&g).unwrap_err().node_id(), a);
+
+    let g = Graph::<(), ()>::new();
+    assert_eq!(transitive_closure(&g), Ok(vec![]));
+    assert_eq!(transitive_reduction(&g), Ok(vec![]));
+}
+
+#[test]
+fn test_k_shortest_paths() {
+    // example from https://en.wikipedia.org/wiki/Yen%27s_algorithm
+    let mut g = Graph::new();
+    let c = g.add_node("C");
+    let d = g.add_node("D");
+    let e = g.add_node("E");
+    let f = g.add_node("F");
+    let gg = g.add_node("G");
+    let h = g.add_node("H");
+    let z = g.add_node("Z");
+    g.add_edge(c, d, 3);
+    g.add_edge(c, e, 2);
+    g.add_edge(d, f, 4);
+    g.add_edge(e, d, 1);
+    g.add_edge(e, f, 2);
+    g.add_edge(e, gg, 3);
+    g.add_edge(f, gg, 2);
+    g.add_edge(f, h, 1);
+    g.add_edge(gg, h, 2);
+
+    let paths = k_shortest_paths(&g, c, h, 3, |e| *e.weight());
+    assert_eq!(paths, vec![
+        (5, vec![c, e, f, h]),
+        (7, vec![c, e, gg, h]),
+        (8, vec![c, d, f, h]),
+    ]);
+
+    // all loopless paths, in nondecreasing order
+    let paths = k_shortest_paths(&g, c, h, 100, |e| *e.weight());
+    assert_eq!(paths.len(), 7);
+    assert!(paths.windows(2).all(|w| w[0].0 <= w[1].0));
+    assert_eq!(set(paths.iter().map(|p| p.1.clone())).len(), paths.len());
+    assert_eq!(paths[6].0, 11);
+
+    assert_eq!(k_shortest_paths(&g, c, h, 0, |e| *e.weight()), vec![]);
+    assert_eq!(k_shortest_paths(&g, c, z, 3, |e| *e.weight()), vec![]);
+    assert_eq!(k_shortest_paths(&g, c, c, 3, |e| *e.weight()), vec![(0, vec![c])]);
+
+    // undirected, with a parallel edge
+    let mut g = Graph::new_undirected();
+    let a = g.add_node("A");
+    let b = g.add_node("B");
+    let c = g.add_node("C");
+    g.add_edge(a, b, 1.);
+    g.add_edge(b, a, 5.);
+    g.add_edge(b, c, 1.);
+    g.add_edge(a, c, 3.);
+    let paths = k_shortest_paths(&g, a, c, 5, |e| *e.weight());
+    assert_eq!(paths, vec![(2., vec![a, b, c]), (3., vec![a, c])]);
+}
+
+#[test]
+fn test_bidirectional_dijkstra() {
+    let mut g = Graph::new();
+    let a = g.add_node("A");
+    let b = g.add_node("B");
+    let c = g.add_node("C");
+    let d = g.add_node("D");
+    let e = g.add_node("E");
+    let f = g.add_node("F");
+    g.add_edge(a, b, 7);
+    g.add_edge(c, a, 9);
+    g.add_edge(a, d, 14);
+    g.add_edge(b, c, 10);
+    g.add_edge(d, c, 2);
+    g.add_edge(d, e, 9);
+    g.add_edge(b, f, 15);
+    g.add_edge(c, f, 11);
+    g.add_edge(e, f, 6);
+
+    assert_eq!(bidirectional_dijkstra(&g, a, e, |e| *e.weight()), Some((23, vec![a, d, e])));
+    assert_eq!(bidirectional_dijkstra(&g, a, a, |e| *e.weight()), Some((0, vec![a])));
+    assert_eq!(bidirectional_dijkstra(&g, e, b, |e| *e.weight()), None);
+
+    // check against dijkstra
+    for start in g.node_indices() {
+        let scores = dijkstra(&g, start, None, |e| *e.weight());
+        for end in g.node_indices() {
+            let path = bidirectional_dijkstra(&g, start, end, |e| *e.weight());
+            assert_eq!(path.as_ref().map(|p| p.0), scores.get(&end).cloned());
+            if let Some((cost, path)) = path {
+                assert_eq!(path[0], start);
+                assert_eq!(path[path.len() - 1], end);
+                let sum: i32 = path.windows(2)
+                    .map(|w| g[g.find_edge(w[0], w[1]).unwrap()]).sum();
+                assert_eq!(sum, cost);
+            }
+        }
+    }
+
+    // undirected
+    let mut g = g.into_edge_type::<Undirected>();
+    g.add_node("Z");
+    assert_eq!(bidirectional_dijkstra(&g, e, a, |e| *e.weight()), Some((20, vec![e, d, c, a])));
+    assert_eq!(bidirectional_dijkstra(&g, e, n(6), |e| *e.weight()), None);
+}
+
+#[test]
+fn dijkstra_walker() {
+    use petgraph::visit::Dijkstra;
+
+    let mut g = Graph::new_undirected();
+    let a = g.add_node("A");
+    let b = g.add_node("B");
+    let c = g.add_node("C");
+    let d = g.add_node("D");
+    let e = g.add_node("E");
+    let f = g.add_node("F");
+    let z = g.add_node("Z");
+    g.add_edge(a, b, 7);
+    g.add_edge(c, a, 9);
+    g.add_edge(a, d, 14);
+    g.add_edge(b, c, 10);
+    g.add_edge(d, c, 2);
+    g.add_edge(d, e, 9);
+    g.add_edge(b, f, 15);
+    g.add_edge(c, f, 11);
+    g.add_edge(e, f, 6);
+
+    // settled in nondecreasing order, with the same distances as dijkstra
+    let order: Vec<_> = Dijkstra::new(&g, a, |e| *e.weight()).iter(&g).collect();
+    assert_eq!(order.len(), 6);
+    assert!(order.windows(2).all(|w| w[0].1 <= w[1].1));
+    assert_eq!(order[0], (a, 0));
+    assert_eq!(order.iter().cloned().collect::<HashMap<_, _>>(),
+               dijkstra(&g, a, None, |e| *e.weight()));
+
+    // stop early, then continue from another node
+    let mut walker = Dijkstra::new(&g, a, |e| *e.weight());
+    assert_eq!(walker.next(&g), Some((a, 0)));
+    assert_eq!(walker.next(&g), Some((b, 7)));
+    assert_eq!(walker.scores[&c], 9);
+    walker.move_to(z);
+    assert_eq!(walker.next(&g), Some((z, 0)));
+    assert_eq!(walker.next(&g), None);
+    assert!(walker.finished.is_visited(&b));
+    assert!(!walker.finished.is_visited(&c));
+    assert!(!walker.scores.contains_key(&c));
+
+    // nodes that were queued but not finished are reached from the new start
+    let mut h = Graph::new();
+    let p = h.add_node("P");
+    let q = h.add_node("Q");
+    let r = h.add_node("R");
+    let s = h.add_node("S");
+    h.extend_with_edges(&[(p, q, 1), (p, r, 1), (s, q, 5)]);
+    let mut resumed = Dijkstra::new(&h, p, |e| *e.weight());
+    assert_eq!(resumed.next(&h), Some((p, 0)));
+    resumed.move_to(s);
+    assert_eq!(resumed.next(&h), Some((s, 0)));
+    assert_eq!(resumed.next(&h), Some((q, 5)));
+    assert_eq!(resumed.next(&h), None);
+    assert_eq!(resumed.scores, vec![(p, 0), (q, 5), (s, 0)].into_iter().collect());
+
+    // several start nodes
+    walker.reset(&g);
+    walker.add_start(e);
+    walker.add_start(b);
+    let order: Vec<_> = walker.iter(&g).collect();
+    assert_eq!(set(order[..2].iter().cloned()), set(vec![(b, 0), (e, 0)]));
+    assert_eq!(set(order), set(vec![(a, 7), (b, 0), (c, 10), (d, 9), (e, 0), (f, 6)]));
+}
+
 #[test]
 fn test_astar_null_heuristic() {
     let mut g = Graph::new();
@@ -415,6 +1468,158 @@ fn test_astar_manhattan_heuristic() {
     }
 }
 
+#[test]
+fn test_floyd_warshall() {
+    let mut g = Graph::new();
+    let a = g.add_node("A");
+    let b = g.add_node("B");
+    let c = g.add_node("C");
+    let d = g.add_node("D");
+    let e = g.add_node("E");
+    g.add_edge(a, b, 4f64);
+    g.add_edge(a, c, 1.);
+    g.add_edge(c, b, 2.);
+    g.add_edge(b, d, -1.);
+    g.add_edge(d, a, 3.);
+
+    let (distance, next) = floyd_warshall(&g).unwrap();
+    assert_eq!(distance[a.index()][b.index()], 3.);
+    assert_eq!(distance[a.index()][d.index()], 2.);
+    assert_eq!(distance[d.index()][b.index()], 6.);
+    assert_eq!(distance[b.index()][b.index()], 0.);
+    assert!(distance[a.index()][e.index()].is_infinite());
+    assert_eq!(next[a.index()][d.index()], Some(c));
+    assert_eq!(next[c.index()][d.index()], Some(b));
+    assert_eq!(next[b.index()][d.index()], Some(d));
+    assert_eq!(next[a.index()][e.index()], None);
+    assert_eq!(next[a.index()][a.index()], None);
+
+    // agrees with dijkstra on the non-negative part of the graph
+    let mut h = g.clone();
+    h.retain_edges(|g, e| g[e] >= 0.);
+    let (distance, _) = floyd_warshall(&h).unwrap();
+    for start in h.node_indices() {
+        let scores = dijkstra(&h, start, None, |e| *e.weight());
+        for end in h.node_indices() {
+            let dist = distance[start.index()][end.index()];
+            assert_eq!(scores.get(&end).cloned().unwrap_or(1./0.), dist);
+        }
+    }
+
+    g.add_edge(d, c, -3.);
+    assert!(floyd_warshall(&g).is_err());
+
+    // a negative undirected edge is a negative cycle by itself
+    let mut u = Graph::new_undirected();
+    let a = u.add_node(());
+    let b = u.add_node(());
+    let c = u.add_node(());
+    u.add_edge(a, b, 2.);
+    u.add_edge(b, c, 3.);
+    let (distance, next) = floyd_warshall(&u).unwrap();
+    assert_eq!(distance[c.index()][a.index()], 5.);
+    assert_eq!(next[c.index()][a.index()], Some(b));
+    u.add_edge(a, c, -1.);
+    assert!(floyd_warshall(&u).is_err());
+}
+
+#[test]
+fn test_johnson() {
+    let mut g = Graph::new();
+    let a = g.add_node("A");
+    let b = g.add_node("B");
+    let c = g.add_node("C");
+    let d = g.add_node("D");
+    let e = g.add_node("E");
+    g.add_edge(a, b, 4f64);
+    g.add_edge(a, c, 1.);
+    g.add_edge(c, b, 2.);
+    g.add_edge(b, d, -1.);
+    g.add_edge(d, a, 3.);
+    g.add_edge(e, d, -5.);
+
+    let distances = johnson(&g).unwrap();
+    let (fw, _) = floyd_warshall(&g).unwrap();
+    assert_eq!(distances.len(), g.node_count());
+    for start in g.node_indices() {
+        for end in g.node_indices() {
+            let dist = distances[&start].get(&end).cloned().unwrap_or(1. / 0.);
+            assert_eq!(dist, fw[start.index()][end.index()]);
+        }
+    }
+    assert_eq!(distances[&e][&b], 1.);
+    assert!(!distances[&a].contains_key(&e));
+
+    g.add_edge(d, c, -3.);
+    assert!(johnson(&g).is_err());
+}
+
+/// Check that `cycle` is a cycle of negative weights in `g`
+fn assert_negative_cycle<Ty>(g: &Graph<(), f64, Ty>, cycle: &NegativeCycle<NodeIndex, EdgeIndex>)
+    where Ty: EdgeType,
+{
+    let nodes = cycle.nodes();
+    let edges = cycle.edges();
+    assert!(!nodes.is_empty());
+    assert_eq!(nodes.len(), edges.len());
+    let mut weight = 0.;
+    for (i, &e) in edges.iter().enumerate() {
+        let (a, b) = g.edge_endpoints(e).unwrap();
+        let (from, to) = (nodes[i], nodes[(i + 1) % nodes.len()]);
+        assert!((a, b) == (from, to) || (!g.is_directed() && (b, a) == (from, to)));
+        weight += g[e];
+    }
+    assert!(weight < 0., "cycle {:?} has weight {}", cycle, weight);
+}
+
+#[test]
+fn negative_cycle() {
+    let mut g = Graph::new();
+    let a = g.add_node(());
+    let b = g.add_node(());
+    let c = g.add_node(());
+    let d = g.add_node(());
+    let e = g.add_node(());
+    g.add_edge(a, b, 1.);
+    g.add_edge(b, c, 2.);
+    g.add_edge(c, d, -1.);
+    g.add_edge(d, b, -2.);
+    g.add_edge(d, e, 5.);
+    let cycle = bellman_ford(&g, a).unwrap_err();
+    assert_negative_cycle(&g, &cycle);
+    assert_eq!(set(cycle.nodes().iter().cloned()), set(vec![b, c, d]));
+    assert_negative_cycle(&g, &floyd_warshall(&g).unwrap_err());
+    assert_negative_cycle(&g, &johnson(&g).unwrap_err());
+
+    // the cycle is not reachable from e
+    assert!(bellman_ford(&g, e).is_ok());
+
+    // a self loop
+    let mut g = Graph::new();
+    let a = g.add_node(());
+    let b = g.add_node(());
+    g.add_edge(a, b, 1.);
+    let bb = g.add_edge(b, b, -1.);
+    let cycle = bellman_ford(&g, a).unwrap_err();
+    assert_eq!(cycle.nodes(), &[b]);
+    assert_eq!(cycle.edges(), &[bb]);
+    assert_negative_cycle(&g, &floyd_warshall(&g).unwrap_err());
+    assert_negative_cycle(&g, &johnson(&g).unwrap_err());
+
+    // an undirected edge of negative weight
+    let mut g = Graph::new_undirected();
+    let a = g.add_node(());
+    let b = g.add_node(());
+    let c = g.add_node(());
+    g.add_edge(a, b, 1.);
+    g.add_edge(c, b, -1.);
+    let cycle = bellman_ford(&g, a).unwrap_err();
+    assert_negative_cycle(&g, &cycle);
+    assert_eq!(set(cycle.nodes().iter().cloned()), set(vec![b, c]));
+    assert_negative_cycle(&g, &floyd_warshall(&g).unwrap_err());
+    assert_negative_cycle(&g, &johnson(&g).unwrap_err());
+}
+
 #[cfg(feature = "generate")]
 #[test]
 fn test_generate_undirected() {
@@ -573,6 +1778,54 @@ fn test_toposort_eq() {
     assert_eq!(petgraph::algo::toposort(&g, None), Ok(vec![a, b]));
 }
 
+#[test]
+fn test_toposort_with_cycle() {
+    use petgraph::algo::toposort_with_cycle;
+
+    let mut g = Graph::<_, ()>::new();
+    let a = g.add_node("A");
+    let b = g.add_node("B");
+    let c = g.add_node("C");
+    let d = g.add_node("D");
+    let e = g.add_node("E");
+    g.extend_with_edges(&[(a, b), (b, c), (c, d), (d, b), (d, e)]);
+
+    let order = toposort_with_cycle(&g, None);
+    let cycle = order.unwrap_err();
+    assert_eq!(cycle.scc(), &[b, c, d]);
+    let mut nodes = cycle.nodes().to_vec();
+    nodes.sort();
+    assert_eq!(nodes, vec![b, c, d]);
+    assert!(nodes.contains(&cycle.node_id()));
+    for i in 0..3 {
+        assert!(g.contains_edge(cycle.nodes()[i], cycle.nodes()[(i + 1) % 3]));
+    }
+
+    // the cycle is a shortcut inside a larger component
+    g.add_edge(c, b, ());
+    let cycle = toposort_with_cycle(&g, None).unwrap_err();
+    assert_eq!(cycle.scc(), &[b, c, d]);
+    let n = cycle.nodes().len();
+    assert!(n == 2 || n == 3);
+    for i in 0..n {
+        assert!(g.contains_edge(cycle.nodes()[i], cycle.nodes()[(i + 1) % n]));
+    }
+
+    // self loop
+    let mut g = Graph::<_, ()>::new();
+    let a = g.add_node("A");
+    let b = g.add_node("B");
+    g.extend_with_edges(&[(a, b), (b, b)]);
+    let mut space = DfsSpace::new(&g);
+    let cycle = toposort_with_cycle(&g, Some(&mut space)).unwrap_err();
+    assert_eq!(cycle.nodes(), &[b]);
+    assert_eq!(cycle.scc(), &[b]);
+
+    let bb = g.find_edge(b, b).unwrap();
+    g.remove_edge(bb);
+    assert_eq!(toposort_with_cycle(&g, Some(&mut space)), Ok(vec![a, b]));
+}
+
 #[test]
 fn is_cyclic_directed() {
     let mut gr = Graph::<_,_>::new();
@@ -12,7 +12,8 @@ mod utils;
 use utils::Small;
 
 use odds::prelude::*;
-use std::collections::HashSet;
+use std::cmp;
+use std::collections::{HashMap, HashSet};
 use std::hash::Hash;
 
 use rand::Rng;
@@ -25,17 +26,47 @@ use petgraph::{
 };
 use petgraph::dot::{Dot, Config};
 use petgraph::algo::{
+    coloring,
     condensation,
     min_spanning_tree,
     is_cyclic_undirected,
     is_cyclic_directed,
+    has_path_connecting,
     is_isomorphic,
     is_isomorphic_matching,
     toposort,
+    toposort_with_cycle,
     kosaraju_scc,
     tarjan_scc,
     dijkstra,
     bellman_ford,
+    floyd_warshall,
+    johnson,
+    k_shortest_paths,
+    bidirectional_dijkstra,
+    dag_shortest_paths,
+    dag_longest_path,
+    max_flow,
+    min_cost_flow,
+    stoer_wagner_min_cut,
+    gomory_hu_tree,
+    maximum_bipartite_matching,
+    maximum_matching,
+    kuhn_munkres,
+    bipartite_coloring,
+    OddCycle,
+    articulation_points,
+    bridges,
+    biconnected_components,
+    eulerian_circuit,
+    eulerian_path,
+    EulerianError,
+    simple_cycles,
+    cycle_basis,
+    girth,
+    shortest_cycle_through,
+    transitive_closure,
+    transitive_reduction,
 };
 use petgraph::visit::{Topo, Reversed};
 use petgraph::visit::{
@@ -45,7 +76,8 @@ use petgraph::visit::{
     EdgeRef,
 };
 use petgraph::data::FromElements;
-use petgraph::graph::{IndexType, node_index, edge_index};
+use petgraph::unionfind::UnionFind;
+use petgraph::graph::{IndexType, EdgeReference, node_index, edge_index};
 use petgraph::graphmap::{
     NodeTrait,
 };
@@ -799,6 +831,902 @@ quickcheck! {
     }
 }
 
+quickcheck! {
+    // johnson and floyd_warshall must agree, including on negative cycles
+    fn johnson_floyd_warshall(gr: Small<Graph<(), i8>>) -> bool {
+        let gr = gr.map(|_, _| (), |_, &w| w as f64);
+        match (johnson(&gr), floyd_warshall(&gr)) {
+            (Ok(j), Ok((fw, _))) => {
+                gr.node_indices().all(|a| gr.node_indices().all(|b| {
+                    let dist = j[&a].get(&b).cloned().unwrap_or(1. / 0.);
+                    dist == fw[a.index()][b.index()]
+                }))
+            }
+            (Err(_), Err(_)) => true,
+            _ => false,
+        }
+    }
+}
+
+quickcheck! {
+    // the error of bellman_ford holds a cycle of negative weight
+    fn bellman_ford_negative_cycle(gr: Small<Graph<(), i8>>, node: usize) -> bool {
+        let gr = gr.map(|_, _| (), |_, &w| w as f64);
+        if gr.node_count() == 0 {
+            return true;
+        }
+        let start = node_index(node % gr.node_count());
+        match bellman_ford(&gr, start) {
+            Ok(_) => true,
+            Err(cycle) => {
+                let nodes = cycle.nodes();
+                let mut weight = 0.;
+                for (i, &e) in cycle.edges().iter().enumerate() {
+                    let next = nodes[(i + 1) % nodes.len()];
+                    if gr.edge_endpoints(e) != Some((nodes[i], next)) {
+                        return false;
+                    }
+                    weight += gr[e];
+                }
+                !nodes.is_empty() && nodes.len() == cycle.edges().len() && weight < 0.
+            }
+        }
+    }
+}
+
+quickcheck! {
+    // k_shortest_paths returns distinct valid loopless paths in cost order,
+    // starting with a shortest path
+    fn k_shortest_paths_valid(gr: Small<Graph<(), u8>>, a: usize, b: usize) -> bool {
+        if gr.node_count() == 0 {
+            return true;
+        }
+        let start = node_index(a % gr.node_count());
+        let goal = node_index(b % gr.node_count());
+        let paths = k_shortest_paths(&*gr, start, goal, 10, |e| *e.weight() as u32);
+        let dist = dijkstra(&*gr, start, Some(goal), |e| *e.weight() as u32);
+        if paths.first().map(|p| p.0) != dist.get(&goal).cloned() {
+            return false;
+        }
+        let valid = paths.iter().all(|&(cost, ref path)| {
+            let mut sum = 0;
+            for w in path.windows(2) {
+                match gr.edges(w[0]).filter(|e| e.target() == w[1]).map(|e| *e.weight()).min() {
+                    Some(c) => sum += c as u32,
+                    None => return false,
+                }
+            }
+            path[0] == start && path[path.len() - 1] == goal &&
+                set(path.iter().cloned()).len() == path.len() && sum == cost
+        });
+        valid && paths.windows(2).all(|w| w[0].0 <= w[1].0) &&
+            set(paths.iter().map(|p| p.1.clone())).len() == paths.len()
+    }
+}
+
+quickcheck! {
+    // bidirectional_dijkstra finds paths of the same cost as dijkstra
+    fn bidirectional_dijkstra_cost(gr: Graph<(), u8>, a: usize, b: usize) -> bool {
+        if gr.node_count() == 0 {
+            return true;
+        }
+        let start = node_index(a % gr.node_count());
+        let goal = node_index(b % gr.node_count());
+        let scores = dijkstra(&gr, start, None, |e| *e.weight() as u32);
+        let path = bidirectional_dijkstra(&gr, start, goal, |e| *e.weight() as u32);
+        path.map(|p| p.0) == scores.get(&goal).cloned()
+    }
+
+    fn bidirectional_dijkstra_cost_undir(gr: Graph<(), u8, Undirected>, a: usize, b: usize) -> bool {
+        if gr.node_count() == 0 {
+            return true;
+        }
+        let start = node_index(a % gr.node_count());
+        let goal = node_index(b % gr.node_count());
+        let scores = dijkstra(&gr, start, None, |e| *e.weight() as u32);
+        let path = bidirectional_dijkstra(&gr, start, goal, |e| *e.weight() as u32);
+        path.map(|p| p.0) == scores.get(&goal).cloned()
+    }
+}
+
+#[test]
+fn dag_paths_bellman_ford() {
+    // shortest and longest paths in a DAG agree with bellman_ford
+    fn prop(DAG(gr): DAG<()>, weights: Vec<i8>, node: usize) -> bool {
+        if gr.node_count() == 0 || weights.is_empty() {
+            return true;
+        }
+        let gr = gr.map(|_, _| (), |e, _| weights[e.index() % weights.len()] as f64);
+        let start = node_index(node % gr.node_count());
+        let (shortest, _) = dag_shortest_paths(&gr, start, |e| *e.weight()).unwrap();
+        let (longest, longest_pred) = dag_longest_path(&gr, start, |e| *e.weight()).unwrap();
+        let (bf, _) = bellman_ford(&gr, start).unwrap();
+        let (bf_neg, _) = bellman_ford(&gr.map(|_, _| (), |_, &w| -w), start).unwrap();
+        gr.node_indices().all(|n| {
+            let short = shortest.get(&n).cloned().unwrap_or(1. / 0.);
+            let long = longest.get(&n).map(|&w| -w).unwrap_or(1. / 0.);
+            let pred_ok = match longest_pred.get(&n) {
+                Some(&p) => gr.edges(p).any(|e| {
+                    e.target() == n && longest[&p] + *e.weight() == longest[&n]
+                }),
+                None => n == start || !longest.contains_key(&n),
+            };
+            short == bf[n.index()] && long == bf_neg[n.index()] && pred_ok
+        })
+    }
+    quickcheck::quickcheck(prop as fn(_, _, _) -> bool);
+}
+
+quickcheck! {
+    // max_flow returns a valid flow whose value is the capacity of the cut
+    fn max_flow_min_cut(gr: Small<Graph<(), u8>>, a: usize, b: usize) -> bool {
+        if gr.node_count() < 2 {
+            return true;
+        }
+        let source = node_index(a % gr.node_count());
+        let mut sink = node_index(b % gr.node_count());
+        if source == sink {
+            sink = node_index((b + 1) % gr.node_count());
+        }
+        let flow = max_flow(&*gr, source, sink, |e| *e.weight() as u32);
+        let mut excess = vec![0i64; gr.node_count()];
+        for edge in gr.edge_references() {
+            let f = flow.flow(edge.id()).unwrap();
+            if f > *edge.weight() as u32 {
+                return false;
+            }
+            excess[edge.source().index()] -= f as i64;
+            excess[edge.target().index()] += f as i64;
+        }
+        let conserved = gr.node_indices().all(|n| {
+            n == source || n == sink || excess[n.index()] == 0
+        });
+        let cut = flow.min_cut();
+        let cut_capacity: u32 = gr.edge_references()
+            .filter(|e| cut.contains(&e.source()) && !cut.contains(&e.target()))
+            .map(|e| *e.weight() as u32)
+            .sum();
+        conserved && excess[sink.index()] == flow.value() as i64 &&
+            cut.contains(&source) && !cut.contains(&sink) && cut_capacity == flow.value()
+    }
+
+    fn max_flow_min_cut_undir(gr: Small<Graph<(), u8, Undirected>>, a: usize, b: usize) -> bool {
+        if gr.node_count() < 2 {
+            return true;
+        }
+        let source = node_index(a % gr.node_count());
+        let mut sink = node_index(b % gr.node_count());
+        if source == sink {
+            sink = node_index((b + 1) % gr.node_count());
+        }
+        let flow = max_flow(&*gr, source, sink, |e| *e.weight() as u32);
+        let cut = flow.min_cut();
+        let cut_capacity: u32 = gr.edge_references()
+            .filter(|e| cut.contains(&e.source()) != cut.contains(&e.target()))
+            .map(|e| *e.weight() as u32)
+            .sum();
+        let bounded = gr.edge_references().all(|e| {
+            flow.flow(e.id()).unwrap() <= *e.weight() as u32
+        });
+        bounded && cut.contains(&source) && !cut.contains(&sink) &&
+            cut_capacity == flow.value()
+    }
+}
+
+/// Return `true` if the graph has a cycle of negative weight.
+fn has_negative_cycle(gr: &Graph<(), f64>) -> bool {
+    // bellman_ford from a new node with an edge to every other node
+    let mut gr = gr.clone();
+    let start = gr.add_node(());
+    for n in gr.node_indices() {
+        if n != start {
+            gr.add_edge(start, n, 0.);
+        }
+    }
+    bellman_ford(&gr, start).is_err()
+}
+
+/// Map a random weight to a cost that is rarely negative.
+fn flow_cost(w: i8) -> i32 {
+    if w < -100 { -1 } else { (w % 8).abs() as i32 }
+}
+
+quickcheck! {
+    // min_cost_flow finds a maximum flow with no negative cycle in its residual
+    // graph, unless the graph itself has a negative cycle
+    fn min_cost_flow_optimal(gr: Small<Graph<(u8, i8), (u8, i8)>>, a: usize, b: usize) -> bool {
+        if gr.node_count() < 2 {
+            return true;
+        }
+        let source = node_index(a % gr.node_count());
+        let mut sink = node_index(b % gr.node_count());
+        if source == sink {
+            sink = node_index((b + 1) % gr.node_count());
+        }
+        let cap = |e: EdgeReference<(u8, i8)>| (e.weight().0 % 8) as i32;
+        let cost = |e: EdgeReference<(u8, i8)>| flow_cost(e.weight().1);
+        let flow = match min_cost_flow(&*gr, source, sink, cap, cost) {
+            Ok(flow) => flow,
+            Err(cycle) => {
+                let positive = gr.filter_map(|_, _| Some(()), |_, &(c, w)| {
+                    if c % 8 > 0 { Some(flow_cost(w) as f64) } else { None }
+                });
+                let weight: i32 = cycle.edges().iter().map(|&e| flow_cost(gr[e].1)).sum();
+                return has_negative_cycle(&positive) && weight < 0;
+            }
+        };
+        if flow.value() != max_flow(&*gr, source, sink, cap).value() {
+            return false;
+        }
+        let mut residual = gr.map(|_, _| (), |_, _| 0.);
+        residual.clear_edges();
+        let mut total_cost = 0;
+        let mut excess = vec![0; gr.node_count()];
+        for e in gr.edge_references() {
+            let f = flow.flow(e.id()).unwrap();
+            if f < 0 || f > cap(e) {
+                return false;
+            }
+            total_cost += f * cost(e);
+            excess[e.source().index()] -= f;
+            excess[e.target().index()] += f;
+            if f < cap(e) {
+                residual.add_edge(e.source(), e.target(), cost(e) as f64);
+            }
+            if f > 0 {
+                residual.add_edge(e.target(), e.source(), -cost(e) as f64);
+            }
+        }
+        let conserved = gr.node_indices().all(|n| {
+            n == source || n == sink || excess[n.index()] == 0
+        });
+        conserved && excess[sink.index()] == flow.value() && total_cost == flow.cost() &&
+            !has_negative_cycle(&residual)
+    }
+}
+
+quickcheck! {
+    // the global minimum cut is the least max flow from one node to any other
+    fn stoer_wagner_max_flow(gr: Small<Graph<(), u8, Undirected>>) -> bool {
+        let result = stoer_wagner_min_cut(&*gr, |e| *e.weight() as u32);
+        if gr.node_count() < 2 {
+            return result.is_none();
+        }
+        let (weight, side) = result.unwrap();
+        let s = node_index(0);
+        let least_flow = gr.node_indices().skip(1)
+            .map(|t| max_flow(&*gr, s, t, |e| *e.weight() as u32).value())
+            .min().unwrap();
+        let side_weight: u32 = gr.edge_references()
+            .filter(|e| side.contains(&e.source()) != side.contains(&e.target()))
+            .map(|e| *e.weight() as u32)
+            .sum();
+        !side.is_empty() && side.len() < gr.node_count() &&
+            weight == least_flow && weight == side_weight
+    }
+}
+
+quickcheck! {
+    // the Gomory–Hu tree gives the max flow value and a minimum cut of that
+    // value between pairs of nodes
+    fn gomory_hu_max_flow(gr: Small<Graph<(), u8, Undirected>>) -> bool {
+        let tree = gomory_hu_tree(&*gr, |e| *e.weight() as u32);
+        if tree.tree().node_count() != gr.node_count() ||
+            tree.tree().edge_count() != gr.node_count().saturating_sub(1)
+        {
+            return false;
+        }
+        let n = gr.node_count();
+        let pairs = (0..n).flat_map(|i| vec![(i, 0), (i, (i * 5 + 1) % n)]);
+        pairs.map(|(i, j)| (node_index(i), node_index(j))).all(|(a, b)| {
+            let cut = tree.min_cut(a, b);
+            if a == b {
+                return cut.is_none();
+            }
+            let (value, side) = cut.unwrap();
+            let side_weight: u32 = gr.edge_references()
+                .filter(|e| side.contains(&e.source()) != side.contains(&e.target()))
+                .map(|e| *e.weight() as u32)
+                .sum();
+            value == max_flow(&*gr, a, b, |e| *e.weight() as u32).value() &&
+                side.contains(&a) && !side.contains(&b) && side_weight == value
+        })
+    }
+}
+
+quickcheck! {
+    // maximum_bipartite_matching finds a matching as large as the max flow
+    // through the bipartite graph
+    fn bipartite_matching_max_flow(gr: Small<Graph<(), (), Undirected>>) -> bool {
+        let is_left = |n: NodeIndex| n.index() % 2 == 0;
+        let left = gr.node_indices().filter(|&n| is_left(n));
+        let matching = maximum_bipartite_matching(&*gr, left);
+
+        let mut matched = HashSet::new();
+        for &e in &matching {
+            let (a, b) = gr.edge_endpoints(e).unwrap();
+            if is_left(a) == is_left(b) || !matched.insert(a) || !matched.insert(b) {
+                return false;
+            }
+        }
+
+        let mut network = Graph::new();
+        for _ in gr.node_indices() {
+            network.add_node(());
+        }
+        let source = network.add_node(());
+        let sink = network.add_node(());
+        for e in gr.edge_references() {
+            let (a, b) = (e.source(), e.target());
+            if is_left(a) && !is_left(b) {
+                network.add_edge(a, b, 1);
+            } else if is_left(b) && !is_left(a) {
+                network.add_edge(b, a, 1);
+            }
+        }
+        for n in gr.node_indices() {
+            if is_left(n) {
+                network.add_edge(source, n, 1);
+            } else {
+                network.add_edge(n, sink, 1);
+            }
+        }
+        matching.len() == max_flow(&network, source, sink, |e| *e.weight()).value()
+    }
+}
+
+/// Check that `edges` are edges of `g` with no endpoint in common.
+fn is_valid_matching<I>(g: &Graph<(), (), Undirected>, edges: I) -> bool
+    where I: IntoIterator<Item=EdgeIndex>,
+{
+    let mut matched = HashSet::new();
+    edges.into_iter().all(|e| {
+        let (a, b) = g.edge_endpoints(e).unwrap();
+        a != b && matched.insert(a) && matched.insert(b)
+    })
+}
+
+/// The size of a maximum matching among the nodes in `free`, by trying them all.
+fn brute_force_matching(adjacent: &[Vec<bool>], free: u32) -> usize {
+    if free == 0 {
+        return 0;
+    }
+    let a = free.trailing_zeros() as usize;
+    let rest = free & !(1 << a);
+    let mut best = brute_force_matching(adjacent, rest);
+    for b in 0..adjacent.len() {
+        if rest & (1 << b) != 0 && adjacent[a][b] {
+            best = cmp::max(best, 1 + brute_force_matching(adjacent, rest & !(1 << b)));
+        }
+    }
+    best
+}
+
+/// The minimum cost of assigning each row of `costs` a distinct column, by
+/// trying them all.
+fn brute_force_assignment(costs: &[Vec<Option<i32>>], row: usize, used: &mut [bool])
+    -> Option<i32>
+{
+    if row == costs.len() {
+        return Some(0);
+    }
+    let mut best = None;
+    for j in 0..used.len() {
+        if let (false, Some(c)) = (used[j], costs[row][j]) {
+            used[j] = true;
+            if let Some(rest) = brute_force_assignment(costs, row + 1, used) {
+                if best.map_or(true, |b| c + rest < b) {
+                    best = Some(c + rest);
+                }
+            }
+            used[j] = false;
+        }
+    }
+    best
+}
+
+/// Check that `result` is a two-coloring of `g`, or an odd cycle whose
+/// consecutive nodes are joined by edges in either direction.
+fn is_bipartite_result<Ty: EdgeType>(g: &Graph<(), (), Ty>,
+                                     result: Result<Vec<bool>, OddCycle<NodeIndex>>) -> bool
+{
+    match result {
+        Ok(coloring) => {
+            coloring.len() == g.node_count() &&
+                g.edge_references().all(|e| {
+                    coloring[e.source().index()] != coloring[e.target().index()]
+                })
+        }
+        Err(cycle) => {
+            let nodes = cycle.nodes();
+            let distinct: HashSet<_> = nodes.iter().collect();
+            nodes.len() % 2 == 1 && distinct.len() == nodes.len() &&
+                (0..nodes.len()).all(|i| {
+                    let (a, b) = (nodes[i], nodes[(i + 1) % nodes.len()]);
+                    g.find_edge(a, b).is_some() || g.find_edge(b, a).is_some()
+                })
+        }
+    }
+}
+
+/// Check that `colors` is a proper coloring of `g` with `n_colors` colors.
+fn is_coloring<Ty: EdgeType>(g: &Graph<(), (), Ty>, colors: &HashMap<NodeIndex, usize>,
+                             n_colors: usize) -> bool
+{
+    let used: HashSet<_> = colors.values().cloned().collect();
+    colors.len() == g.node_count() && used == (0..n_colors).collect() &&
+        g.edge_references().all(|e| {
+            e.source() == e.target() || colors[&e.source()] != colors[&e.target()]
+        })
+}
+
+/// Count the connected components of `g` without the node `skip_node` and
+/// the edges in `skip_edges`.
+fn count_components(g: &Graph<(), (), Undirected>, skip_node: Option<NodeIndex>,
+                    skip_edges: &[EdgeIndex]) -> usize
+{
+    let mut components = UnionFind::new(g.node_count());
+    for e in g.edge_references() {
+        if skip_edges.contains(&e.id()) || skip_node == Some(e.source()) ||
+            skip_node == Some(e.target())
+        {
+            continue;
+        }
+        components.union(e.source().index(), e.target().index());
+    }
+    let mut roots: Vec<_> = g.node_indices()
+                             .filter(|&n| Some(n) != skip_node)
+                             .map(|n| components.find(n.index()))
+                             .collect();
+    roots.sort();
+    roots.dedup();
+    roots.len()
+}
+
+/// Check the result of `eulerian_circuit` (if `closed`) or `eulerian_path`.
+fn is_eulerian_result<Ty: EdgeType>(g: &Graph<(), (), Ty>,
+                                    result: Result<Vec<EdgeIndex>, EulerianError<NodeIndex>>,
+                                    closed: bool) -> bool
+{
+    let mut balance = vec![0; g.node_count()];
+    let mut components = UnionFind::new(g.node_count());
+    for e in g.edge_references() {
+        let (a, b) = (e.source().index(), e.target().index());
+        balance[a] += 1;
+        balance[b] += if g.is_directed() { -1 } else { 1 };
+        components.union(a, b);
+    }
+    let unbalanced: Vec<_> = g.node_indices().filter(|n| {
+        let b = balance[n.index()];
+        if g.is_directed() { b != 0 } else { b % 2 != 0 }
+    }).collect();
+    match result {
+        Ok(walk) => {
+            if walk.len() != g.edge_count() ||
+                walk.iter().collect::<HashSet<_>>().len() != walk.len()
+            {
+                return false;
+            }
+            if walk.is_empty() {
+                return true;
+            }
+            // follow the walk from either end of the first edge
+            let (a, b) = g.edge_endpoints(walk[0]).unwrap();
+            let starts = if g.is_directed() { vec![a] } else { vec![a, b] };
+            starts.into_iter().any(|start| {
+                let mut node = start;
+                for &e in &walk {
+                    let (a, b) = g.edge_endpoints(e).unwrap();
+                    if a == node {
+                        node = b;
+                    } else if b == node && !g.is_directed() {
+                        node = a;
+                    } else {
+                        return false;
+                    }
+                }
+                !closed || node == start
+            })
+        }
+        Err(EulerianError::Unbalanced(nodes)) => {
+            let fits = !closed && unbalanced.len() == 2 && (!g.is_directed() ||
+                balance[unbalanced[0].index()] * balance[unbalanced[1].index()] == -1);
+            nodes == unbalanced && !unbalanced.is_empty() && !fits
+        }
+        Err(EulerianError::Disconnected) => {
+            let roots: HashSet<_> = g.edge_references()
+                                     .map(|e| components.find(e.source().index()))
+                                     .collect();
+            roots.len() > 1
+        }
+    }
+}
+
+/// Find the simple cycles of `g` that start at their smallest node, by
+/// extending every path from each node.
+fn brute_force_cycles(g: &Graph<(), ()>) -> Vec<Vec<NodeIndex>> {
+    fn extend(g: &Graph<(), ()>, path: &mut Vec<NodeIndex>, cycles: &mut Vec<Vec<NodeIndex>>) {
+        let last = *path.last().unwrap();
+        let mut next: Vec<_> = g.neighbors(last).collect();
+        next.sort();
+        next.dedup();
+        for n in next {
+            if n == path[0] {
+                cycles.push(path.clone());
+            } else if n > path[0] && !path.contains(&n) {
+                path.push(n);
+                extend(g, path, cycles);
+                path.pop();
+            }
+        }
+    }
+    let mut cycles = Vec::new();
+    for a in g.node_indices() {
+        extend(g, &mut vec![a], &mut cycles);
+    }
+    cycles
+}
+
+/// The length of a shortest cycle through the edge `e`: one more than the
+/// distance between its ends without it.
+fn shortest_cycle_through_edge(g: &Graph<(), (), Undirected>, e: EdgeIndex) -> Option<usize> {
+    let (a, b) = g.edge_endpoints(e).unwrap();
+    if a == b {
+        return Some(1);
+    }
+    let mut dist = vec![None; g.node_count()];
+    dist[a.index()] = Some(0);
+    let mut queue = vec![a];
+    let mut i = 0;
+    while i < queue.len() {
+        let x = queue[i];
+        i += 1;
+        for edge in g.edges(x) {
+            let y = if edge.source() == x { edge.target() } else { edge.source() };
+            if edge.id() != e && dist[y.index()].is_none() {
+                dist[y.index()] = Some(dist[x.index()].unwrap() + 1);
+                queue.push(y);
+            }
+        }
+    }
+    dist[b.index()].map(|d| d + 1)
+}
+
+quickcheck! {
+    // maximum_matching agrees with maximum_bipartite_matching on bipartite graphs
+    fn maximum_matching_bipartite(gr: Small<Graph<(), (), Undirected>>) -> bool {
+        // keep only the edges between even and odd nodes
+        let is_left = |n: NodeIndex| n.index() % 2 == 0;
+        let gr = gr.filter_map(|_, &w| Some(w), |e, &w| {
+            let (a, b) = gr.edge_endpoints(e).unwrap();
+            if is_left(a) != is_left(b) { Some(w) } else { None }
+        });
+        let matching = maximum_matching(&gr);
+        is_valid_matching(&gr, matching.edges()) &&
+            matching.edges().count() == matching.len() &&
+            matching.len() == maximum_bipartite_matching(&gr, gr.node_indices().filter(|&n| is_left(n))).len()
+    }
+
+    // maximum_matching is as large as any matching of a small graph
+    fn maximum_matching_brute_force(gr: Small<Graph<(), (), Undirected>>) -> bool {
+        let gr = gr.filter_map(|n, &w| if n.index() < 10 { Some(w) } else { None },
+                               |_, &w| Some(w));
+        let n = gr.node_count();
+        let mut adjacent = vec![vec![false; n]; n];
+        for e in gr.edge_references() {
+            adjacent[e.source().index()][e.target().index()] = true;
+            adjacent[e.target().index()][e.source().index()] = true;
+        }
+        let matching = maximum_matching(&gr);
+        for a in gr.node_indices() {
+            if let Some(b) = matching.mate(a) {
+                if matching.mate(b) != Some(a) {
+                    return false;
+                }
+            }
+        }
+        is_valid_matching(&gr, matching.edges()) &&
+            matching.len() == brute_force_matching(&adjacent, (1 << n) - 1) &&
+            matching.is_perfect() == (2 * matching.len() == n)
+    }
+    // kuhn_munkres finds a cheapest assignment of the smaller side
+    fn kuhn_munkres_brute_force(gr: Small<Graph<(), i8, Undirected>>) -> bool {
+        let gr = gr.filter_map(|n, &w| if n.index() < 10 { Some(w) } else { None },
+                               |_, &w| Some(w));
+        let is_left = |n: NodeIndex| n.index() % 2 == 0;
+        let left: Vec<_> = gr.node_indices().filter(|&n| is_left(n)).collect();
+        let mut right = Vec::new();
+        for e in gr.edge_references() {
+            for &(a, b) in &[(e.source(), e.target()), (e.target(), e.source())] {
+                if is_left(a) && !is_left(b) && !right.contains(&b) {
+                    right.push(b);
+                }
+            }
+        }
+        let cost = |a: NodeIndex, b: NodeIndex| {
+            gr.edges(a).filter(|e| e.target() == b).map(|e| *e.weight() as i32).min()
+        };
+        let (rows, cols) = if left.len() <= right.len() { (&left, &right) } else { (&right, &left) };
+        let costs: Vec<Vec<_>> = rows.iter().map(|&a| {
+            cols.iter().map(|&b| cost(a, b)).collect()
+        }).collect();
+        let expected = brute_force_assignment(&costs, 0, &mut vec![false; cols.len()]);
+
+        match kuhn_munkres(&gr, left.clone(), |e| *e.weight() as i32) {
+            None => expected.is_none(),
+            Some((total, matching)) => {
+                let sum: i32 = matching.iter().map(|&e| gr[e] as i32).sum();
+                let left_end = |e| {
+                    let (a, b) = gr.edge_endpoints(e).unwrap();
+                    if is_left(a) { a } else { b }
+                };
+                let sorted = matching.windows(2).all(|w| left_end(w[0]) < left_end(w[1]));
+                is_valid_matching(&gr.map(|_, _| (), |_, _| ()), matching.iter().cloned()) &&
+                    matching.len() == rows.len() && sorted &&
+                    sum == total && Some(total) == expected
+            }
+        }
+    }
+
+    // bipartite_coloring finds a proper two-coloring or an odd cycle, and
+    // ignores the direction of edges
+    fn bipartite_coloring_valid(gr: Small<Graph<(), ()>>) -> bool {
+        let undirected = gr.0.clone().into_edge_type::<Undirected>();
+        let directed_result = bipartite_coloring(&*gr);
+        let undirected_result = bipartite_coloring(&undirected);
+        directed_result.is_ok() == undirected_result.is_ok() &&
+            is_bipartite_result(&gr, directed_result) &&
+            is_bipartite_result(&undirected, undirected_result)
+    }
+
+    // a graph whose edges join even and odd nodes is bipartite
+    fn bipartite_coloring_even_odd(gr: Small<Graph<(), (), Undirected>>) -> bool {
+        let gr = gr.filter_map(|_, &w| Some(w), |e, &w| {
+            let (a, b) = gr.edge_endpoints(e).unwrap();
+            if a.index() % 2 != b.index() % 2 { Some(w) } else { None }
+        });
+        bipartite_coloring(&gr).is_ok()
+    }
+
+    // every coloring heuristic finds a proper coloring with at most one
+    // color more than the largest degree
+    fn coloring_valid(gr: Small<Graph<(), ()>>, order: Vec<usize>) -> bool {
+        if gr.node_count() == 0 {
+            return true;
+        }
+        let max_degree = gr.node_indices().map(|a| {
+            gr.neighbors_undirected(a).filter(|&b| b != a).collect::<HashSet<_>>().len()
+        }).max().unwrap_or(0);
+        let order = order.into_iter().map(|i| node_index(i % gr.node_count()));
+        [
+            coloring::greedy_coloring(&*gr, order),
+            coloring::dsatur_coloring(&*gr),
+            coloring::welsh_powell_coloring(&*gr),
+        ].iter().all(|&(ref colors, n_colors)| {
+            is_coloring(&gr, colors, n_colors) && n_colors <= max_degree + 1
+        })
+    }
+
+    // dsatur_coloring uses two colors on bipartite graphs
+    fn dsatur_coloring_bipartite(gr: Small<Graph<(), (), Undirected>>) -> bool {
+        let gr = gr.filter_map(|_, &w| Some(w), |e, &w| {
+            let (a, b) = gr.edge_endpoints(e).unwrap();
+            if a.index() % 2 != b.index() % 2 { Some(w) } else { None }
+        });
+        let (colors, n_colors) = coloring::dsatur_coloring(&gr);
+        is_coloring(&gr, &colors, n_colors) && n_colors <= 2
+    }
+
+    // edge_coloring gives the edges at each node different colors
+    fn edge_coloring_valid(gr: Small<Graph<(), ()>>) -> bool {
+        let (colors, n_colors) = coloring::edge_coloring(&*gr);
+        let mut seen = HashSet::new();
+        gr.edge_references().all(|e| {
+            if e.source() == e.target() {
+                return !colors.contains_key(&e.id());
+            }
+            let c = colors[&e.id()];
+            c < n_colors && seen.insert((e.source(), c)) && seen.insert((e.target(), c))
+        })
+    }
+
+    // without parallel edges, edge_coloring uses at most one color more
+    // than the largest degree
+    fn edge_coloring_simple(gr: Small<Graph<(), (), Undirected>>) -> bool {
+        let mut pairs = HashSet::new();
+        let gr = gr.filter_map(|_, &w| Some(w), |e, &w| {
+            let (a, b) = gr.edge_endpoints(e).unwrap();
+            if a != b && pairs.insert((cmp::min(a, b), cmp::max(a, b))) { Some(w) } else { None }
+        });
+        let (colors, n_colors) = coloring::edge_coloring(&gr);
+        let max_degree = gr.node_indices().map(|a| gr.neighbors(a).count()).max().unwrap_or(0);
+        let mut seen = HashSet::new();
+        gr.edge_references().all(|e| {
+            let c = colors[&e.id()];
+            c < n_colors && seen.insert((e.source(), c)) && seen.insert((e.target(), c))
+        }) && n_colors <= max_degree + 1
+    }
+    // articulation_points and bridges are the nodes and edges whose removal
+    // adds a connected component
+    fn articulation_points_and_bridges(gr: Small<Graph<(), (), Undirected>>) -> bool {
+        let n = count_components(&gr, None, &[]);
+        let points = articulation_points(&*gr);
+        let cut_edges = bridges(&*gr);
+        gr.node_indices().all(|a| {
+            // the node's own component goes away if it has no other nodes
+            let alone = gr.neighbors(a).all(|b| b == a);
+            let after = count_components(&gr, Some(a), &[]) + if alone { 1 } else { 0 };
+            points.contains(&a) == (after > n)
+        }) && gr.edge_indices().all(|e| {
+            cut_edges.contains(&e) == (count_components(&gr, None, &[e]) > n)
+        })
+    }
+
+    // biconnected_components splits the edges into 2-connected parts that
+    // only meet at articulation points
+    fn biconnected_components_valid(gr: Small<Graph<(), (), Undirected>>) -> bool {
+        let components = biconnected_components(&*gr);
+        let points = articulation_points(&*gr);
+        let mut seen = HashSet::new();
+        let mut n_components = vec![0; gr.node_count()];
+        for component in &components {
+            let mut sub = Graph::<(), (), Undirected>::with_capacity(0, 0);
+            let mut nodes = HashMap::new();
+            for &e in component {
+                if !seen.insert(e) {
+                    return false;
+                }
+                let (a, b) = gr.edge_endpoints(e).unwrap();
+                let a = *nodes.entry(a).or_insert_with(|| sub.add_node(()));
+                let b = *nodes.entry(b).or_insert_with(|| sub.add_node(()));
+                sub.add_edge(a, b, ());
+            }
+            if count_components(&sub, None, &[]) != 1 ||
+                sub.node_count() > 2 &&
+                    sub.node_indices().any(|a| count_components(&sub, Some(a), &[]) != 1)
+            {
+                return false;
+            }
+            for a in nodes.keys() {
+                n_components[a.index()] += 1;
+            }
+        }
+        let loops = gr.edge_references().filter(|e| e.source() == e.target()).count();
+        seen.len() + loops == gr.edge_count() &&
+            gr.node_indices().all(|a| points.contains(&a) == (n_components[a.index()] > 1))
+    }
+
+    // eulerian_circuit and eulerian_path walk every edge once, or explain why
+    // they can't
+    fn eulerian_valid_directed(gr: Small<Graph<(), ()>>) -> bool {
+        is_eulerian_result(&gr, eulerian_circuit(&*gr), true) &&
+            is_eulerian_result(&gr, eulerian_path(&*gr), false)
+    }
+
+    fn eulerian_valid_undirected(gr: Small<Graph<(), (), Undirected>>) -> bool {
+        is_eulerian_result(&gr, eulerian_circuit(&*gr), true) &&
+            is_eulerian_result(&gr, eulerian_path(&*gr), false)
+    }
+
+    // doubling every edge of a connected graph, or adding its reverse in a
+    // directed graph, makes it Eulerian
+    fn eulerian_doubled(gr: Small<Graph<(), ()>>) -> bool {
+        let mut directed = gr.0.clone();
+        let mut undirected = gr.0.clone().into_edge_type::<Undirected>();
+        let mut components = UnionFind::new(gr.node_count());
+        for e in gr.edge_references() {
+            directed.add_edge(e.target(), e.source(), ());
+            undirected.add_edge(e.source(), e.target(), ());
+            components.union(e.source().index(), e.target().index());
+        }
+        let roots: HashSet<_> = gr.edge_references()
+                                  .map(|e| components.find(e.source().index()))
+                                  .collect();
+        let connected = roots.len() <= 1;
+        let circuit = eulerian_circuit(&directed);
+        let undirected_circuit = eulerian_circuit(&undirected);
+        circuit.is_ok() == connected && undirected_circuit.is_ok() == connected &&
+            is_eulerian_result(&directed, circuit, true) &&
+            is_eulerian_result(&undirected, undirected_circuit, true)
+    }
+    // simple_cycles finds each simple cycle once
+    fn simple_cycles_brute_force(gr: Small<Graph<(), ()>>) -> bool {
+        let gr = gr.filter_map(|n, &w| if n.index() < 8 { Some(w) } else { None },
+                               |_, &w| Some(w));
+        let mut cycles: Vec<_> = simple_cycles(&gr).collect();
+        let mut expected = brute_force_cycles(&gr);
+        cycles.sort();
+        expected.sort();
+        cycles == expected
+    }
+
+    // cycle_basis has one valid cycle for each edge outside a spanning forest
+    fn cycle_basis_valid(gr: Small<Graph<(), (), Undirected>>) -> bool {
+        let basis = cycle_basis(&*gr);
+        let components = count_components(&gr, None, &[]);
+        basis.len() + gr.node_count() == gr.edge_count() + components &&
+            basis.iter().all(|cycle| {
+                let distinct: HashSet<_> = cycle.iter().collect();
+                distinct.len() == cycle.len() && (0..cycle.len()).all(|i| {
+                    gr.find_edge(cycle[i], cycle[(i + 1) % cycle.len()]).is_some()
+                })
+            })
+    }
+
+    // shortest_cycle_through and girth agree with the shortest cycles through
+    // each edge
+    fn shortest_cycles(gr: Small<Graph<(), (), Undirected>>) -> bool {
+        let through_edge: Vec<_> = gr.edge_indices()
+                                     .map(|e| shortest_cycle_through_edge(&gr, e))
+                                     .collect();
+        let expected_girth = through_edge.iter().filter_map(|&l| l).min();
+        girth(&*gr) == expected_girth && gr.node_indices().all(|a| {
+            let expected = gr.edges(a).filter_map(|e| through_edge[e.id().index()]).min();
+            match shortest_cycle_through(&*gr, a) {
+                None => expected.is_none(),
+                Some(cycle) => {
+                    let distinct: HashSet<_> = cycle.iter().collect();
+                    cycle[0] == a && Some(cycle.len()) == expected &&
+                        distinct.len() == cycle.len() && (0..cycle.len()).all(|i| {
+                            gr.find_edge(cycle[i], cycle[(i + 1) % cycle.len()]).is_some()
+                        })
+                }
+            }
+        })
+    }
+
+    // toposort_with_cycle agrees with toposort, and its cycle and component
+    // agree with tarjan_scc
+    fn toposort_cycle(gr: Graph<(), ()>) -> bool {
+        match toposort_with_cycle(&gr, None) {
+            Ok(order) => toposort(&gr, None) == Ok(order),
+            Err(cycle) => {
+                let nodes = cycle.nodes();
+                let distinct: HashSet<_> = nodes.iter().collect();
+                let mut scc = tarjan_scc(&gr).into_iter()
+                    .find(|c| c.contains(&nodes[0])).unwrap();
+                scc.sort();
+                toposort(&gr, None).is_err() && scc == cycle.scc() &&
+                    distinct.len() == nodes.len() &&
+                    nodes.iter().all(|n| scc.contains(n)) &&
+                    (0..nodes.len()).all(|i| {
+                        gr.find_edge(nodes[i], nodes[(i + 1) % nodes.len()]).is_some()
+                    })
+            }
+        }
+    }
+
+    // transitive_closure agrees with has_path_connecting, and the
+    // transitive_reduction is a minimal subset of the edges with the same
+    // closure
+    fn transitive(dag: DAG<()>) -> bool {
+        let gr = dag.0;
+        let closure = transitive_closure(&gr).unwrap();
+        let expected: Vec<_> = gr.node_indices().flat_map(|a| {
+            gr.node_indices()
+              .filter(|&b| a != b && has_path_connecting(&gr, a, b, None))
+              .map(|b| (a, b))
+              .collect::<Vec<_>>()
+        }).collect();
+        let reduction = transitive_reduction(&gr).unwrap();
+        let mut reduced = Graph::<(), ()>::with_capacity(gr.node_count(), 0);
+        for _ in gr.node_indices() {
+            reduced.add_node(());
+        }
+        reduced.extend_with_edges(&reduction);
+        closure == expected &&
+            transitive_closure(&reduced) == Ok(closure) &&
+            reduction.iter().all(|&(a, b)| {
+                gr.contains_edge(a, b) && {
+                    let mut without = reduced.clone();
+                    let e = without.find_edge(a, b).unwrap();
+                    without.remove_edge(e);
+                    !has_path_connecting(&without, a, b, None)
+                }
+            })
+    }
+}
+
 defmac!(iter_eq a, b => a.eq(b));
 defmac!(nodes_eq ref a, ref b => a.node_references().eq(b.node_references()));
 defmac!(edgew_eq ref a, ref b => a.edge_references().eq(b.edge_references()));
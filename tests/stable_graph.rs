@@ -4,10 +4,24 @@ extern crate petgraph;
 extern crate itertools;
 #[macro_use] extern crate defmac;
 
+use std::collections::HashSet;
+
 use petgraph::prelude::*;
 use petgraph::stable_graph::node_index as n;
 use petgraph::EdgeType;
-use petgraph::algo::{kosaraju_scc, tarjan_scc};
+use petgraph::algo::{
+    kosaraju_scc,
+    tarjan_scc,
+    max_flow,
+    stoer_wagner_min_cut,
+    gomory_hu_tree,
+    maximum_matching,
+    bipartite_coloring,
+    coloring,
+    simple_cycles,
+    transitive_closure,
+    transitive_reduction,
+};
 use petgraph::visit::{
     NodeIndexable,
     IntoNodeReferences,
@@ -343,3 +357,145 @@ fn from() {
     assert!(edgew_eq!(gr5, ans));
     assert!(edges_eq!(gr5, ans));
 }
+
+#[test]
+fn test_max_flow() {
+    // a stable graph with a removed node
+    let mut sg = StableGraph::new();
+    let s = sg.add_node(());
+    let x = sg.add_node(());
+    let a = sg.add_node(());
+    let t = sg.add_node(());
+    sg.add_edge(s, x, 5);
+    sg.add_edge(x, t, 5);
+    sg.add_edge(s, a, 2);
+    let at = sg.add_edge(a, t, 3);
+    sg.remove_node(x);
+    let flow = max_flow(&sg, s, t, |e| *e.weight());
+    assert_eq!(flow.value(), 2);
+    assert_eq!(flow.flow(at), Some(2));
+    assert_eq!(flow.min_cut(), &[s]);
+}
+
+#[test]
+fn test_stoer_wagner_min_cut() {
+    // fewer than two nodes have no cut, and removed nodes are skipped
+    let mut s = StableGraph::<(), f64>::new();
+    assert_eq!(stoer_wagner_min_cut(&s, |e| *e.weight()), None);
+    let x = s.add_node(());
+    assert_eq!(stoer_wagner_min_cut(&s, |e| *e.weight()), None);
+    let y = s.add_node(());
+    let z = s.add_node(());
+    s.add_edge(x, z, 1.);
+    s.remove_node(y);
+    let (weight, side) = stoer_wagner_min_cut(&s, |e| *e.weight()).unwrap();
+    assert_eq!(weight, 1.);
+    assert!(side == vec![x] || side == vec![z]);
+}
+
+#[test]
+fn test_gomory_hu_tree() {
+    // the tree holds the graph's node ids, even with holes in a StableGraph
+    let mut sg = StableGraph::<(), f64, Undirected>::with_capacity(0, 0);
+    let a = sg.add_node(());
+    let x = sg.add_node(());
+    let b = sg.add_node(());
+    let c = sg.add_node(());
+    sg.add_edge(a, b, 2.);
+    sg.add_edge(x, c, 1.);
+    sg.remove_node(x);
+    let tree = gomory_hu_tree(&sg, |e| *e.weight());
+    let mut nodes: Vec<_> = tree.tree().node_indices().map(|n| tree.tree()[n]).collect();
+    nodes.sort();
+    assert_eq!(nodes, vec![a, b, c]);
+    assert_eq!(tree.min_cut(b, a), Some((2., vec![b])));
+    assert_eq!(tree.min_cut(a, c), Some((0., vec![a, b])));
+    assert_eq!(tree.min_cut(a, x), None);
+}
+
+#[test]
+fn test_maximum_matching() {
+    // removed nodes are not counted, and edge directions are ignored
+    let mut s = StableGraph::<(), ()>::new();
+    let a = s.add_node(());
+    let b = s.add_node(());
+    let x = s.add_node(());
+    let c = s.add_node(());
+    let ba = s.add_edge(b, a, ());
+    s.add_edge(a, c, ());
+    s.remove_node(x);
+    assert!(!maximum_matching(&s).is_perfect());
+    s.add_edge(b, c, ());
+    let d = s.add_node(());
+    s.add_edge(d, c, ());
+    let matching = maximum_matching(&s);
+    assert!(matching.is_perfect());
+    assert_eq!(matching.edges().count(), 2);
+    assert!(matching.edges().any(|e| e == ba));
+}
+
+#[test]
+fn test_bipartite_coloring() {
+    // the coloring is indexed by node index, holes included
+    let mut s = StableGraph::<(), (), Undirected>::with_capacity(0, 0);
+    let a = s.add_node(());
+    let x = s.add_node(());
+    let b = s.add_node(());
+    let c = s.add_node(());
+    s.add_edge(a, c, ());
+    s.add_edge(c, b, ());
+    s.remove_node(x);
+    let coloring = bipartite_coloring(&s).unwrap();
+    assert_eq!(coloring.len(), 4);
+    assert!(!coloring[a.index()] && coloring[c.index()] && !coloring[b.index()]);
+}
+
+#[test]
+fn test_edge_coloring() {
+    // parallel edges get colors of their own, and self loops none
+    let mut m = StableGraph::<(), (), Undirected>::with_capacity(0, 0);
+    let a = m.add_node(());
+    let b = m.add_node(());
+    let c = m.add_node(());
+    let ab = m.add_edge(a, b, ());
+    let ab2 = m.add_edge(b, a, ());
+    let bc = m.add_edge(b, c, ());
+    let cc = m.add_edge(c, c, ());
+    let (colors, n_colors) = coloring::edge_coloring(&m);
+    assert_eq!(n_colors, 3);
+    assert!(!colors.contains_key(&cc));
+    let at_b: HashSet<_> = [ab, ab2, bc].iter().map(|e| colors[e]).collect();
+    assert_eq!(at_b.len(), 3);
+}
+
+#[test]
+fn test_simple_cycles() {
+    // a DAG has none, parallel edges add none, and holes are skipped
+    let mut s = StableGraph::<(), ()>::new();
+    let a = s.add_node(());
+    let x = s.add_node(());
+    let b = s.add_node(());
+    let c = s.add_node(());
+    s.extend_with_edges(&[(a, b), (b, c), (a, c), (x, a), (c, x)]);
+    s.remove_node(x);
+    assert_eq!(simple_cycles(&s).count(), 0);
+    s.add_edge(c, a, ());
+    s.add_edge(c, a, ());
+    let mut cycles: Vec<_> = simple_cycles(&s).collect();
+    cycles.sort();
+    assert_eq!(cycles, vec![vec![a, b, c], vec![a, c]]);
+}
+
+#[test]
+fn test_transitive() {
+    // the result uses the node ids, so holes in a StableGraph are skipped
+    let mut s = StableGraph::<(), ()>::new();
+    let a = s.add_node(());
+    let b = s.add_node(());
+    let c = s.add_node(());
+    let d = s.add_node(());
+    s.extend_with_edges(&[(d, c), (c, a), (d, a), (b, d)]);
+    s.remove_node(b);
+    assert_eq!(transitive_reduction(&s), Ok(vec![(c, a), (d, c)]));
+    assert_eq!(transitive_closure(&s), Ok(vec![(c, a), (d, a), (d, c)]));
+}